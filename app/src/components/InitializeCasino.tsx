@@ -9,7 +9,7 @@ import { CheckCircle, Loader2, Sparkles, Zap, Coins } from 'lucide-react';
 export const InitializeCasino: FC = () => {
   const { connection } = useConnection();
   const wallet = useWallet();
-  const { program, getCasinoPDA, getVaultPDA, fetchCasino } = useCasino();
+  const { program, getCasinoPDA, getVaultPDA, getTreasuryPDA, fetchCasino } = useCasino();
   
   const [isInitialized, setIsInitialized] = useState<boolean | null>(null);
   const [isInitializing, setIsInitializing] = useState(false);
@@ -86,11 +86,15 @@ export const InitializeCasino: FC = () => {
       const maxBetLamports = new BN(maxBetNum * LAMPORTS_PER_SOL);
       const initialVaultLamports = new BN(initialVaultNum * LAMPORTS_PER_SOL);
 
+      // The authority signs oracle randomness until a dedicated key is set (update_config / queue_change)
+      const oracle = wallet.publicKey;
+
       const tx = await program.methods
-        .initialize(minBetLamports, maxBetLamports, initialVaultLamports)
+        .initialize(minBetLamports, maxBetLamports, initialVaultLamports, oracle)
         .accounts({
           casino: getCasinoPDA.pda,
           vault: getVaultPDA.pda,
+          treasury: getTreasuryPDA.pda,
           authority: wallet.publicKey,
           systemProgram: SystemProgram.programId,
         })
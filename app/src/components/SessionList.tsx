@@ -4,7 +4,6 @@ import { PublicKey } from '@solana/web3.js';
 import { useBet } from '../hooks/useBet';
 import { useFulfillRandomness } from '../hooks/useFulfillRandomness';
 import { useVrfResolve } from '../hooks/useVrfResolve';
-import { useCasino } from '../hooks/useCasino';
 import { formatLamportsToSol, shortPubkey } from '../utils/format';
 import { Clock, RefreshCw, History } from 'lucide-react';
 
@@ -32,9 +31,7 @@ export const SessionList: FC = () => {
   const { fetchSessions, isFetchingSessions } = useBet();
   const { fulfillRandomness, isFulfilling } = useFulfillRandomness();
   const { refundExpired, isRefunding } = useVrfResolve();
-  const { fetchCasino } = useCasino();
   const [sessions, setSessions] = useState<Session[]>([]);
-  const [casinoAuthority, setCasinoAuthority] = useState<string | null>(null);
   const [currentTime, setCurrentTime] = useState<number>(Math.floor(Date.now() / 1000));
 
   const loadSessions = async () => {
@@ -42,13 +39,6 @@ export const SessionList: FC = () => {
     setSessions(fetchedSessions as any);
   };
 
-  const loadCasinoAuthority = async () => {
-    const casinoData = await fetchCasino();
-    if (casinoData) {
-      setCasinoAuthority(casinoData.authority.toBase58());
-    }
-  };
-
   const handleResolve = async (sessionPubkey: string, playerPubkey: string) => {
     const result = await fulfillRandomness(
       new PublicKey(sessionPubkey),
@@ -85,7 +75,6 @@ export const SessionList: FC = () => {
   useEffect(() => {
     if (publicKey) {
       loadSessions();
-      loadCasinoAuthority();
     }
   }, [publicKey]);
 
@@ -172,7 +161,7 @@ export const SessionList: FC = () => {
                               </span>
                             )}
                           </div>
-                          {publicKey && !timeLeft.expired && casinoAuthority === publicKey.toBase58() && (
+                          {publicKey && !timeLeft.expired && session.account.player === publicKey.toBase58() && (
                             <button
                               onClick={() => handleResolve(session.publicKey, session.account.player)}
                               disabled={isFulfilling}
@@ -1,6 +1,6 @@
 import { useState } from 'react';
 import { useConnection, useWallet } from '@solana/wallet-adapter-react';
-import { SystemProgram, SYSVAR_RENT_PUBKEY, SYSVAR_CLOCK_PUBKEY } from '@solana/web3.js';
+import { PublicKey, SystemProgram, SYSVAR_RENT_PUBKEY, SYSVAR_CLOCK_PUBKEY } from '@solana/web3.js';
 import BN from 'bn.js';
 import { useCasino } from './useCasino';
 import { NETWORK, getClusterUrl } from '../utils/constants';
@@ -19,9 +19,15 @@ export function useBet() {
    * @param gameType 'coinflip' | 'dice' | 'slots'
    * @param choice Game-specific: CoinFlip(0/1), Dice(2-12), Slots(0)
    * @param betAmountLamports Bet amount in lamports (BN, required for Anchor u64)
+   * @param randomnessAccount Committed Switchboard randomness account (Switchboard casinos only)
    * @returns {betTx, gameId, sessionPda} for modal/auto-resolve tracking, or null on error
    */
-  const placeBet = async (gameType: 'coinflip' | 'dice' | 'slots', choice: number, betAmountLamports: BN): Promise<{betTx: string; gameId: number; sessionPda: string} | null> => {
+  const placeBet = async (
+    gameType: 'coinflip' | 'dice' | 'slots',
+    choice: number,
+    betAmountLamports: BN,
+    randomnessAccount?: PublicKey
+  ): Promise<{betTx: string; gameId: number; sessionPda: string} | null> => {
     if (!publicKey) {
       toast.error('Please connect your wallet');
       return null;
@@ -81,15 +87,32 @@ export function useBet() {
       const gameTypeEnum = gameType === 'coinflip' ? { coinFlip: {} } : 
                            gameType === 'dice' ? { dice: {} } :
                            { slots: {} };
+
+      // The casino's randomness source decides which proof the bet is bound to
+      const source = Object.keys(casinoAccount.randomnessSource)[0];
+      const isCommitReveal = source === 'commitReveal';
+      const isSwitchboard = source === 'switchboard';
+      if (isSwitchboard && !randomnessAccount) {
+        throw new Error('Switchboard bets need a committed randomness account');
+      }
+
+      // Commit-reveal outcomes mix in a seed the house cannot know in advance
+      const clientSeed = isCommitReveal
+        ? Array.from(crypto.getRandomValues(new Uint8Array(16)), b => b.toString(16).padStart(2, '0')).join('')
+        : null;
+      console.log('  Randomness Source:', source);
       
       const tx = await casino.program.methods
         // Anchor expects BN for u64. Passing a JS number causes `toArrayLike` serialization crash.
-        .placeBet(gameTypeEnum, choice, betAmountLamports)
+        .placeBet(gameTypeEnum, choice, betAmountLamports, clientSeed)
         .accounts({
           casino: casinoPDA,
           session: sessionPDA,
           vault: vaultPDA,
           player: publicKey,
+          playerLimits: casino.getPlayerLimitsPDA(publicKey).pda,
+          randomnessAccount: isSwitchboard ? randomnessAccount : null,
+          seedChain: isCommitReveal ? casino.getSeedChainPDA.pda : null,
           systemProgram: SystemProgram.programId,
           rent: SYSVAR_RENT_PUBKEY,
           clock: SYSVAR_CLOCK_PUBKEY,
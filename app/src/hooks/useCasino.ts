@@ -1,8 +1,19 @@
 import { useMemo } from 'react';
-import { PublicKey } from '@solana/web3.js';
+import { PublicKey, SystemProgram, SYSVAR_CLOCK_PUBKEY, SYSVAR_SLOT_HASHES_PUBKEY } from '@solana/web3.js';
 import { AnchorProvider, Program } from '@coral-xyz/anchor';
 import { useConnection, useWallet } from '@solana/wallet-adapter-react';
-import { PROGRAM_ID, CASINO_SEED, VAULT_SEED, SESSION_SEED } from '../utils/constants';
+import {
+  PROGRAM_ID,
+  CASINO_SEED,
+  VAULT_SEED,
+  SESSION_SEED,
+  TREASURY_SEED,
+  SEED_CHAIN_SEED,
+  PLAYER_SEED,
+  PLAYER_BALANCE_SEED,
+  PLAYER_LIMITS_SEED,
+  VIP_CONFIG_SEED,
+} from '../utils/constants';
 import type { Casino } from '../types/casino';
 import IDL from '../idl/casino.json';
 
@@ -45,6 +56,54 @@ export function useCasino() {
     return { pda, bump };
   }, [getCasinoPDA.pda]);
 
+  /**
+   * Derive Treasury PDA
+   */
+  const getTreasuryPDA = useMemo(() => {
+    const [pda, bump] = PublicKey.findProgramAddressSync(
+      [TREASURY_SEED, getCasinoPDA.pda.toBuffer()],
+      PROGRAM_ID
+    );
+    return { pda, bump };
+  }, [getCasinoPDA.pda]);
+
+  /**
+   * Derive the house seed chain PDA (commit-reveal randomness)
+   */
+  const getSeedChainPDA = useMemo(() => {
+    const [pda, bump] = PublicKey.findProgramAddressSync(
+      [SEED_CHAIN_SEED, getCasinoPDA.pda.toBuffer()],
+      PROGRAM_ID
+    );
+    return { pda, bump };
+  }, [getCasinoPDA.pda]);
+
+  /**
+   * Derive VIP config PDA (the tier table, which may not exist yet)
+   */
+  const getVipConfigPDA = useMemo(() => {
+    const [pda, bump] = PublicKey.findProgramAddressSync(
+      [VIP_CONFIG_SEED, getCasinoPDA.pda.toBuffer()],
+      PROGRAM_ID
+    );
+    return { pda, bump };
+  }, [getCasinoPDA.pda]);
+
+  /**
+   * Derive a per-player PDA of the casino (profile, balance or limits)
+   */
+  const getPlayerScopedPDA = (seed: Uint8Array, player: PublicKey) => {
+    const [pda, bump] = PublicKey.findProgramAddressSync(
+      [seed, getCasinoPDA.pda.toBuffer(), player.toBuffer()],
+      PROGRAM_ID
+    );
+    return { pda, bump };
+  };
+
+  const getPlayerProfilePDA = (player: PublicKey) => getPlayerScopedPDA(PLAYER_SEED, player);
+  const getPlayerBalancePDA = (player: PublicKey) => getPlayerScopedPDA(PLAYER_BALANCE_SEED, player);
+  const getPlayerLimitsPDA = (player: PublicKey) => getPlayerScopedPDA(PLAYER_LIMITS_SEED, player);
+
   /**
    * Derive Session PDA for a player and game ID
    */
@@ -61,6 +120,28 @@ export function useCasino() {
     return { pda, bump };
   };
 
+  /**
+   * Accounts for refund_expired on a SOL session. The session is read to pass
+   * the Switchboard randomness account and balance it was placed with.
+   */
+  const getRefundAccounts = async (sessionPubkey: PublicKey) => {
+    if (!program) throw new Error('Program not initialized');
+
+    const session: any = await program.account.gameSession.fetch(sessionPubkey);
+    return {
+      casino: getCasinoPDA.pda,
+      session: sessionPubkey,
+      vault: getVaultPDA.pda,
+      player: session.player,
+      playerBalance: session.fromBalance ? getPlayerBalancePDA(session.player).pda : null,
+      playerLimits: getPlayerLimitsPDA(session.player).pda,
+      randomnessAccount: session.randomnessRequest.switchboardRequest ?? null,
+      slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
+      systemProgram: SystemProgram.programId,
+      clock: SYSVAR_CLOCK_PUBKEY,
+    };
+  };
+
   /**
    * Fetch casino config account
    */
@@ -231,6 +312,13 @@ export function useCasino() {
     getCasinoPDA,
     getVaultPDA,
     getSessionPDA,
+    getTreasuryPDA,
+    getSeedChainPDA,
+    getVipConfigPDA,
+    getPlayerProfilePDA,
+    getPlayerBalancePDA,
+    getPlayerLimitsPDA,
+    getRefundAccounts,
     fetchCasino,
     fetchVaultBalance,
     fetchPlayerSessions,
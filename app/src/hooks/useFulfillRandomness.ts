@@ -1,6 +1,12 @@
 import { useState } from 'react';
 import { useConnection, useWallet } from '@solana/wallet-adapter-react';
-import { SystemProgram, SYSVAR_CLOCK_PUBKEY, PublicKey } from '@solana/web3.js';
+import {
+  SystemProgram,
+  SYSVAR_CLOCK_PUBKEY,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  SYSVAR_SLOT_HASHES_PUBKEY,
+  PublicKey,
+} from '@solana/web3.js';
 import { useCasino } from './useCasino';
 import { logTransactionError, logTransactionSuccess, getUserFriendlyError } from '../utils/errors';
 import toast from 'react-hot-toast';
@@ -12,7 +18,8 @@ export function useFulfillRandomness() {
   const [isFulfilling, setIsFulfilling] = useState(false);
 
   /**
-   * Fulfill randomness for a pending session (mock VRF for devnet testing)
+   * Settle a pending session from the proof of its randomness source.
+   * The connected wallet settles as the player, so wins are paid out immediately.
    * Returns {txSignature, parsedResult} where parsedResult contains {outcome, isWin} if found in logs
    */
   const fulfillRandomness = async (
@@ -31,29 +38,70 @@ export function useFulfillRandomness() {
       console.log('\n✨ FULFILL_RANDOMNESS START');
       console.log('  Session:', sessionPubkey.toBase58());
       console.log('  Player:', playerPubkey.toBase58());
-      console.log('  Caller (Authority):', wallet.publicKey.toBase58());
+      console.log('  Caller:', wallet.publicKey.toBase58());
 
-      const casinoAccount = await casino.fetchCasino();
-      if (!casinoAccount || casinoAccount.authority.toBase58() !== wallet.publicKey.toBase58()) {
-        throw new Error('Only casino authority can fulfill randomness');
+      if (wallet.publicKey.toBase58() !== playerPubkey.toBase58()) {
+        throw new Error('Only the player can settle from this wallet; other sessions are settled by the house');
       }
 
-      const casinoPDA = casino.getCasinoPDA.pda;
-      const vaultPDA = casino.getVaultPDA.pda;
-
-      // Generate a mock random value (for devnet testing)
-      const randomValue = new Uint8Array(32);
-      randomValue[0] = Math.floor(Math.random() * 256);
-      console.log('  Mock Random Value (first byte):', randomValue[0]);
+      const session: any = await casino.program.account.gameSession.fetch(sessionPubkey);
+      const request = session.randomnessRequest;
+      const source = Object.keys(request.source)[0];
+      console.log('  Randomness Source:', source);
+
+      // Referral rewards accrue to the referrer's profile, which must be passed when set
+      const playerProfilePDA = casino.getPlayerProfilePDA(playerPubkey).pda;
+      const playerProfile: any = await casino.program.account.player.fetchNullable(playerProfilePDA);
+      const referrerProfilePDA = playerProfile?.referrer
+        ? casino.getPlayerProfilePDA(playerProfile.referrer).pda
+        : null;
+
+      // Only the sources the player can prove on their own are settled here;
+      // the value argument is ignored by every source except mock
+      const unusedValue = new Array(32).fill(0);
+      let method;
+      switch (source) {
+        case 'switchboard':
+          method = casino.program.methods.settleWithVrf();
+          break;
+        case 'oracleSigned':
+          if (!request.oracleSignatureHash) {
+            throw new Error('Waiting for the oracle signature');
+          }
+          method = casino.program.methods.fulfillRandomness(unusedValue, null);
+          break;
+        case 'slotHash':
+          method = casino.program.methods.fulfillRandomness(unusedValue, null);
+          break;
+        case 'mock':
+          // Only accepted by localnet builds with the `mock-vrf` feature
+          method = casino.program.methods.fulfillRandomness(
+            Array.from(crypto.getRandomValues(new Uint8Array(32))),
+            null
+          );
+          break;
+        default:
+          throw new Error('Commit-reveal sessions are settled by the house once it reveals its seed');
+      }
 
-      const tx = await casino.program.methods
-        .fulfillRandomness(Array.from(randomValue))
+      const tx = await method
         .accounts({
-          casino: casinoPDA,
+          casino: casino.getCasinoPDA.pda,
           session: sessionPubkey,
-          vault: vaultPDA,
+          vault: casino.getVaultPDA.pda,
           player: playerPubkey,
           caller: wallet.publicKey,
+          settlerRole: null,
+          randomnessAccount: request.switchboardRequest ?? null,
+          seedChain: null,
+          tokenVault: null,
+          playerBalance: session.fromBalance ? casino.getPlayerBalancePDA(playerPubkey).pda : null,
+          playerProfile: playerProfilePDA,
+          referrerProfile: referrerProfilePDA,
+          vipConfig: casino.getVipConfigPDA.pda,
+          playerLimits: casino.getPlayerLimitsPDA(playerPubkey).pda,
+          instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          slotHashes: SYSVAR_SLOT_HASHES_PUBKEY,
           systemProgram: SystemProgram.programId,
           clock: SYSVAR_CLOCK_PUBKEY,
         })
@@ -119,7 +167,7 @@ export function useFulfillRandomness() {
       logTransactionSuccess('FULFILL_RANDOMNESS', txSignature, {
         'Session PDA': sessionPubkey.toBase58(),
         'Player': playerPubkey.toBase58(),
-        'Caller (Authority)': wallet.publicKey.toBase58(),
+        'Caller': wallet.publicKey.toBase58(),
         'Result': parsedResult ? `${parsedResult.isWin ? 'WIN' : 'LOSS'} (outcome: ${parsedResult.outcome})` : 'Unknown',
       });
 
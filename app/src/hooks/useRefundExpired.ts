@@ -1,6 +1,5 @@
 import { useState } from 'react';
 import { useConnection, useWallet } from '@solana/wallet-adapter-react';
-import { Transaction, SystemProgram } from '@solana/web3.js';
 import toast from 'react-hot-toast';
 import { useCasino } from './useCasino';
 import { operatorLog, operatorError } from '../utils/operatorGate';
@@ -64,20 +63,12 @@ export function useRefundExpired() {
     try {
       operatorLog(`Refunding session ${sessionPubkey.slice(0, 8)}... (gameId: ${gameId})`);
 
-      const casinoPDA = casino.getCasinoPDA.pda;
-      const vaultPDA = casino.getVaultPDA.pda;
       const sessionPDA = casino.getSessionPDA(wallet.publicKey, gameId).pda;
 
       // Build refund_expired transaction
       const tx = await casino.program.methods
         .refundExpired()
-        .accounts({
-          casino: casinoPDA,
-          session: sessionPDA,
-          vault: vaultPDA,
-          player: wallet.publicKey,
-          systemProgram: SystemProgram.programId,
-        })
+        .accounts(await casino.getRefundAccounts(sessionPDA))
         .transaction();
 
       const { blockhash, lastValidBlockHeight } = await connection.getLatestBlockhash();
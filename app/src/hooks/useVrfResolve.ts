@@ -1,6 +1,6 @@
 import { useState } from 'react';
 import { useConnection, useWallet } from '@solana/wallet-adapter-react';
-import { SystemProgram, SYSVAR_CLOCK_PUBKEY, PublicKey, Keypair } from '@solana/web3.js';
+import { PublicKey, Keypair } from '@solana/web3.js';
 import { useCasino } from './useCasino';
 import { logTransactionError, logTransactionSuccess, getUserFriendlyError } from '../utils/errors';
 import toast from 'react-hot-toast';
@@ -74,19 +74,9 @@ export function useVrfResolve() {
       console.log('  Session:', sessionPubkey.toBase58());
       console.log('  Player:', playerPubkey.toBase58());
 
-      const casinoPDA = casino.getCasinoPDA.pda;
-      const vaultPDA = casino.getVaultPDA.pda;
-
       const tx = await casino.program.methods
         .refundExpired()
-        .accounts({
-          casino: casinoPDA,
-          session: sessionPubkey,
-          vault: vaultPDA,
-          player: playerPubkey,
-          systemProgram: SystemProgram.programId,
-          clock: SYSVAR_CLOCK_PUBKEY,
-        })
+        .accounts(await casino.getRefundAccounts(sessionPubkey))
         .transaction();
 
       const { blockhash, lastValidBlockHeight } = await connection.getLatestBlockhash('finalized');
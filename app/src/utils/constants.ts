@@ -21,6 +21,12 @@ const encoder = new TextEncoder();
 export const CASINO_SEED = encoder.encode('casino');
 export const VAULT_SEED = encoder.encode('vault');
 export const SESSION_SEED = encoder.encode('session');
+export const TREASURY_SEED = encoder.encode('treasury');
+export const SEED_CHAIN_SEED = encoder.encode('seed_chain');
+export const PLAYER_SEED = encoder.encode('player');
+export const PLAYER_BALANCE_SEED = encoder.encode('player_balance');
+export const PLAYER_LIMITS_SEED = encoder.encode('player_limits');
+export const VIP_CONFIG_SEED = encoder.encode('vip_config');
 
 // Network configuration
 export const NETWORK = import.meta.env.VITE_NETWORK || 'devnet';
@@ -14,7 +14,7 @@ pub enum CasinoError {
     #[msg("Vault has insufficient liquidity to cover potential payout")]
     InsufficientVaultLiquidity,
     
-    #[msg("Unauthorized: only casino authority can perform this action")]
+    #[msg("Unauthorized: signer is not the casino authority or lacks the required role")]
     Unauthorized,
     
     #[msg("Invalid randomness callback - verification failed")]
@@ -49,4 +49,133 @@ pub enum CasinoError {
     
     #[msg("Invalid skim amount - must be greater than zero")]
     InvalidSkimAmount,
+    
+    #[msg("Invalid randomness account - wrong owner, layout or session binding")]
+    InvalidRandomnessAccount,
+    
+    #[msg("Randomness was committed too long ago or has already been revealed")]
+    StaleRandomnessCommitment,
+    
+    #[msg("Randomness has not been revealed yet")]
+    RandomnessNotRevealed,
+    
+    #[msg("Server seed does not match the committed hash")]
+    InvalidServerSeed,
+    
+    #[msg("Invalid client seed - must be 1 to 64 bytes")]
+    InvalidClientSeed,
+    
+    #[msg("Randomness accounts or arguments do not match the randomness source")]
+    RandomnessSourceMismatch,
+    
+    #[msg("Slot hash is no longer available")]
+    SlotHashUnavailable,
+    
+    #[msg("This change must be queued through the timelock")]
+    TimelockRequired,
+    
+    #[msg("Timelock delay has not elapsed yet")]
+    TimelockNotElapsed,
+    
+    #[msg("Invalid timelock delay")]
+    InvalidTimelockDelay,
+    
+    #[msg("Invalid withdrawal amount")]
+    InvalidWithdrawalAmount,
+    
+    #[msg("Treasury withdrawal exceeds the per-epoch cap")]
+    TreasuryEpochCapExceeded,
+    
+    #[msg("Treasury has insufficient funds")]
+    InsufficientTreasuryFunds,
+    
+    #[msg("Invalid reserve policy")]
+    InvalidReservePolicy,
+    
+    #[msg("Vault holds no excess above the reserve policy")]
+    NothingToSkim,
+    
+    #[msg("Invalid revenue split: 1-8 distinct beneficiaries whose shares sum to 10000 bp")]
+    InvalidRevenueSplit,
+    
+    #[msg("Beneficiary accounts do not match the revenue split")]
+    BeneficiaryMismatch,
+    
+    #[msg("Vault profit belongs to liquidity providers while the pool is active")]
+    LiquidityPoolActive,
+    
+    #[msg("Invalid liquidity amount")]
+    InvalidLiquidityAmount,
+    
+    #[msg("Withdrawal cooldown has not elapsed")]
+    WithdrawalCooldownActive,
+    
+    #[msg("Vault has no liquidity available for withdrawals")]
+    NoWithdrawableLiquidity,
+    
+    #[msg("Session currency does not match the vault")]
+    MintMismatch,
+    
+    #[msg("Token vault is not accepting bets")]
+    TokenVaultInactive,
+    
+    #[msg("Mint has an extension that breaks vault custody")]
+    UnsupportedMintExtension,
+    
+    #[msg("Mint's transfer hook program is not allowlisted for this vault")]
+    TransferHookNotAllowed,
+    
+    #[msg("Insufficient player balance")]
+    InsufficientPlayerBalance,
+    
+    #[msg("Invalid deposit amount")]
+    InvalidDepositAmount,
+    
+    #[msg("Session key has expired")]
+    SessionKeyExpired,
+    
+    #[msg("Bet is outside the session key's limits")]
+    SessionKeyNotAllowed,
+    
+    #[msg("Invalid session key parameters")]
+    InvalidSessionKey,
+    
+    #[msg("Players cannot refer themselves")]
+    SelfReferral,
+    
+    #[msg("Referrer profile missing or does not match")]
+    InvalidReferrer,
+    
+    #[msg("Referral share exceeds the maximum")]
+    InvalidReferralShare,
+    
+    #[msg("No referral rewards to claim")]
+    NoReferralRewards,
+    
+    #[msg("VIP tiers must have increasing thresholds and a valid rakeback")]
+    InvalidVipTiers,
+    
+    #[msg("No rakeback to claim")]
+    NoRakeback,
+    
+    #[msg("Player is self-excluded")]
+    SelfExcluded,
+    
+    #[msg("Bet exceeds the player's responsible gaming limits")]
+    PlayerLimitExceeded,
+    
+    #[msg("Invalid player limits")]
+    InvalidPlayerLimits,
+    
+    #[msg("Session still has a pending bet or an unclaimed payout")]
+    SessionNotClosable,
+    
+    #[msg("Deposit must wait for its cooldown and a newer NAV snapshot")]
+    DepositCooldownActive,
+    
+    #[msg("Share would leave the beneficiary below the rent-exempt minimum")]
+    BeneficiaryBelowRentExempt,
+    
+    #[msg("Session outcome is already public - it must be settled, not refunded")]
+    SessionNotRefundable,
 }
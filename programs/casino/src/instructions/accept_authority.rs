@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [CASINO_SEED],
+        bump,
+        constraint = casino.pending_authority == Some(new_authority.key()) @ CasinoError::Unauthorized,
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    /// The proposed authority proves it controls the key by signing
+    pub new_authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let casino = &mut ctx.accounts.casino;
+    
+    let old_authority = casino.authority;
+    casino.authority = ctx.accounts.new_authority.key();
+    casino.pending_authority = None;
+    
+    msg!("Authority transferred: {} -> {}", old_authority, casino.authority);
+    
+    emit!(AuthorityTransferred {
+        old_authority,
+        new_authority: casino.authority,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+#[instruction(destination: Pubkey)]
+pub struct AddTreasuryDestination<'info> {
+    #[account(
+        seeds = [CASINO_SEED],
+        bump,
+        has_one = authority @ CasinoError::Unauthorized
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + TreasuryDestination::INIT_SPACE,
+        seeds = [TREASURY_DESTINATION_SEED, casino.key().as_ref(), destination.as_ref()],
+        bump
+    )]
+    pub treasury_destination: Account<'info, TreasuryDestination>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<AddTreasuryDestination>, destination: Pubkey) -> Result<()> {
+    let treasury_destination = &mut ctx.accounts.treasury_destination;
+    
+    treasury_destination.destination = destination;
+    treasury_destination.total_withdrawn = 0;
+    treasury_destination.added_at = Clock::get()?.unix_timestamp;
+    treasury_destination.bump = *ctx.bumps.get("treasury_destination").unwrap();
+    
+    msg!("Treasury destination allowlisted: {}", destination);
+    
+    emit!(TreasuryDestinationAdded { destination });
+    
+    Ok(())
+}
+
+#[event]
+pub struct TreasuryDestinationAdded {
+    pub destination: Pubkey,
+}
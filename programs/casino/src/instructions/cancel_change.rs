@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+pub struct CancelChange<'info> {
+    #[account(
+        seeds = [CASINO_SEED],
+        bump,
+        has_one = authority @ CasinoError::Unauthorized
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        mut,
+        seeds = [
+            PENDING_CHANGE_SEED,
+            casino.key().as_ref(),
+            &pending_change.id.to_le_bytes()
+        ],
+        bump = pending_change.bump,
+        close = proposer,
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+    
+    /// Receives the pending change's rent
+    #[account(
+        mut,
+        address = pending_change.proposer @ CasinoError::Unauthorized,
+    )]
+    pub proposer: SystemAccount<'info>,
+    
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<CancelChange>) -> Result<()> {
+    let pending_change = &ctx.accounts.pending_change;
+    
+    msg!("Change {} cancelled", pending_change.id);
+    
+    emit!(ChangeCancelled {
+        id: pending_change.id,
+        change: pending_change.change.clone(),
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct ChangeCancelled {
+    pub id: u64,
+    pub change: ConfigChange,
+}
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+pub struct CancelWithdrawal<'info> {
+    #[account(
+        seeds = [CASINO_SEED],
+        bump,
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        mut,
+        seeds = [LIQUIDITY_POOL_SEED, casino.key().as_ref()],
+        bump = liquidity_pool.bump,
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+    
+    #[account(
+        mut,
+        seeds = [
+            WITHDRAWAL_REQUEST_SEED,
+            casino.key().as_ref(),
+            withdrawal_request.id.to_le_bytes().as_ref()
+        ],
+        bump = withdrawal_request.bump,
+        has_one = owner @ CasinoError::Unauthorized,
+    )]
+    pub withdrawal_request: Account<'info, WithdrawalRequest>,
+    
+    #[account(
+        mut,
+        seeds = [LP_ESCROW_SEED, casino.key().as_ref()],
+        bump = liquidity_pool.escrow_bump,
+    )]
+    pub lp_escrow: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = liquidity_pool.lp_mint,
+    )]
+    pub owner_shares: Account<'info, TokenAccount>,
+    
+    pub owner: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+/// Return the unfilled shares. The request stays in the queue, empty, so the
+/// queue order is preserved; `process_withdrawal` closes it when it reaches the head.
+pub fn handler(ctx: Context<CancelWithdrawal>) -> Result<()> {
+    let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+    let withdrawal_request = &mut ctx.accounts.withdrawal_request;
+    let shares = withdrawal_request.shares_remaining;
+    
+    require!(shares > 0, CasinoError::InvalidLiquidityAmount);
+    
+    let casino_key = ctx.accounts.casino.key();
+    token::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.lp_escrow.to_account_info(),
+                to: ctx.accounts.owner_shares.to_account_info(),
+                authority: liquidity_pool.to_account_info(),
+            },
+            &[&[LIQUIDITY_POOL_SEED, casino_key.as_ref(), &[liquidity_pool.bump]]],
+        ),
+        shares
+    )?;
+    
+    withdrawal_request.shares_remaining = 0;
+    liquidity_pool.queued_shares = liquidity_pool.queued_shares
+        .checked_sub(shares)
+        .ok_or(CasinoError::Overflow)?;
+    
+    msg!("Withdrawal request {} cancelled, {} shares returned", withdrawal_request.id, shares);
+    
+    emit!(WithdrawalCancelled {
+        id: withdrawal_request.id,
+        owner: withdrawal_request.owner,
+        shares,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct WithdrawalCancelled {
+    pub id: u64,
+    pub owner: Pubkey,
+    pub shares: u64,
+}
@@ -20,6 +20,7 @@ pub struct ClaimPayout<'info> {
             &session.game_id.to_le_bytes()
         ],
         bump = session.bump,
+        constraint = session.mint.is_none() @ CasinoError::MintMismatch,
         constraint = session.status == SessionStatus::Resolved @ CasinoError::NotResolved,
     )]
     pub session: Account<'info, GameSession>,
@@ -67,6 +68,7 @@ pub fn handler(ctx: Context<ClaimPayout>) -> Result<()> {
     ctx.accounts.casino.total_payouts = ctx.accounts.casino.total_payouts
         .checked_add(payout)
         .ok_or(CasinoError::Overflow)?;
+    ctx.accounts.casino.remove_unclaimed_payout(payout)?;
     
     ctx.accounts.session.result.as_mut().unwrap().payout_claimed = true;
     
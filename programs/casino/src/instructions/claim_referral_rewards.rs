@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+pub struct ClaimReferralRewards<'info> {
+    #[account(
+        mut,
+        seeds = [CASINO_SEED],
+        bump,
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, casino.key().as_ref(), authority.key().as_ref()],
+        bump = player.bump,
+        has_one = authority @ CasinoError::Unauthorized,
+    )]
+    pub player: Account<'info, Player>,
+    
+    /// Vault PDA holding the accrued rewards
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, casino.key().as_ref()],
+        bump = casino.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Pay the referrer's accrued rewards out of the vault
+pub fn handler(ctx: Context<ClaimReferralRewards>) -> Result<()> {
+    let casino = &mut ctx.accounts.casino;
+    let player = &mut ctx.accounts.player;
+    let amount = player.referral_rewards;
+    
+    require!(amount > 0, CasinoError::NoReferralRewards);
+    
+    let casino_key = casino.key();
+    let vault_seeds = &[VAULT_SEED, casino_key.as_ref(), &[casino.vault_bump]];
+    let signer_seeds = &[&vault_seeds[..]];
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.authority.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+    )?;
+    
+    casino.remove_referral_reward(amount)?;
+    player.referral_rewards = 0;
+    player.referral_rewards_claimed = player.referral_rewards_claimed
+        .checked_add(amount)
+        .ok_or(CasinoError::Overflow)?;
+    
+    msg!("Claimed {} lamports of referral rewards", amount);
+    
+    emit!(ReferralRewardsClaimed {
+        referrer: player.authority,
+        amount,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct ReferralRewardsClaimed {
+    pub referrer: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::*;
+use crate::errors::CasinoError;
+use crate::token_extensions;
+
+#[derive(Accounts)]
+pub struct ClaimTokenPayout<'info> {
+    #[account(
+        seeds = [CASINO_SEED],
+        bump,
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        mut,
+        seeds = [
+            SESSION_SEED,
+            session.player.as_ref(),
+            &session.game_id.to_le_bytes()
+        ],
+        bump = session.bump,
+        constraint = session.status == SessionStatus::Resolved @ CasinoError::NotResolved,
+        constraint = session.mint == Some(token_vault.mint) @ CasinoError::MintMismatch,
+    )]
+    pub session: Account<'info, GameSession>,
+    
+    #[account(
+        mut,
+        seeds = [TOKEN_VAULT_SEED, casino.key().as_ref(), mint.key().as_ref()],
+        bump = token_vault.bump,
+        has_one = mint,
+        has_one = vault_token_account,
+    )]
+    pub token_vault: Account<'info, TokenVault>,
+    
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(mut)]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+    )]
+    pub player_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Player must sign to claim their winnings
+    #[account(
+        constraint = player.key() == session.player @ CasinoError::Unauthorized,
+    )]
+    pub player: Signer<'info>,
+    
+    #[account(address = token_vault.token_program @ CasinoError::MintMismatch)]
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, ClaimTokenPayout<'info>>) -> Result<()> {
+    let result = ctx.accounts.session.result.as_ref()
+        .ok_or(CasinoError::NotResolved)?;
+    
+    require!(result.is_win && result.payout > 0 && !result.payout_claimed, CasinoError::NothingToClaim);
+    
+    let payout = result.payout;
+    let token_vault = &mut ctx.accounts.token_vault;
+    
+    token_extensions::validate_mint(&ctx.accounts.mint.to_account_info(), token_vault.transfer_hook_program)?;
+    
+    let casino_key = ctx.accounts.casino.key();
+    let mint_key = token_vault.mint;
+    
+    token_extensions::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.player_token_account.to_account_info(),
+                authority: token_vault.to_account_info(),
+            },
+            &[&[TOKEN_VAULT_SEED, casino_key.as_ref(), mint_key.as_ref(), &[token_vault.bump]]],
+        ).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        payout,
+        ctx.accounts.mint.decimals,
+    )?;
+    
+    token_vault.total_payouts = token_vault.total_payouts
+        .checked_add(payout)
+        .ok_or(CasinoError::Overflow)?;
+    token_vault.remove_unclaimed_payout(payout)?;
+    
+    ctx.accounts.session.result.as_mut().unwrap().payout_claimed = true;
+    
+    Ok(())
+}
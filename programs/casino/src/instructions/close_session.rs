@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+pub struct CloseSession<'info> {
+    #[account(
+        mut,
+        seeds = [
+            SESSION_SEED,
+            session.player.as_ref(),
+            &session.game_id.to_le_bytes()
+        ],
+        bump = session.bump,
+        constraint = session.is_closable() @ CasinoError::SessionNotClosable,
+        close = player,
+    )]
+    pub session: Account<'info, GameSession>,
+    
+    /// Player receives the session rent
+    /// CHECK: Player pubkey verified against session
+    #[account(
+        mut,
+        constraint = player.key() == session.player @ CasinoError::Unauthorized,
+    )]
+    pub player: SystemAccount<'info>,
+}
+
+/// Close a resolved session with nothing left to claim and return its rent to
+/// the player. Permissionless, since the rent always goes to the player.
+pub fn handler(ctx: Context<CloseSession>) -> Result<()> {
+    msg!("Session {} closed", ctx.accounts.session.game_id);
+    Ok(())
+}
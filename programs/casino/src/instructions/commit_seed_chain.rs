@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+pub struct CommitSeedChain<'info> {
+    #[account(
+        seeds = [CASINO_SEED],
+        bump,
+        has_one = authority @ CasinoError::Unauthorized
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + SeedChain::INIT_SPACE,
+        seeds = [SEED_CHAIN_SEED, casino.key().as_ref()],
+        bump
+    )]
+    pub seed_chain: Account<'info, SeedChain>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Start a new server-seed chain. Sessions already bound to the previous
+/// chain keep their own commitment and can still be settled with its seeds.
+pub fn handler(ctx: Context<CommitSeedChain>, commitment: [u8; 32]) -> Result<()> {
+    let seed_chain = &mut ctx.accounts.seed_chain;
+    
+    require!(commitment != [0u8; 32], CasinoError::InvalidServerSeed);
+    
+    seed_chain.commitment = commitment;
+    seed_chain.chain_id = seed_chain.chain_id
+        .checked_add(1)
+        .ok_or(CasinoError::Overflow)?;
+    seed_chain.revealed_count = 0;
+    seed_chain.bump = *ctx.bumps.get("seed_chain").unwrap();
+    
+    msg!("Seed chain {} committed", seed_chain.chain_id);
+    
+    emit!(SeedChainCommitted {
+        chain_id: seed_chain.chain_id,
+        commitment,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct SeedChainCommitted {
+    pub chain_id: u64,
+    pub commitment: [u8; 32],
+}
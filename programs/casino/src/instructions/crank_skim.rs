@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+pub struct CrankSkim<'info> {
+    #[account(
+        mut,
+        seeds = [CASINO_SEED],
+        bump,
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, casino.key().as_ref()],
+        bump = casino.vault_bump
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, casino.key().as_ref()],
+        bump = casino.treasury_bump
+    )]
+    pub treasury: SystemAccount<'info>,
+    
+    /// Anyone; receives the crank tip
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Move everything above `casino.reserve_policy` from the vault to the
+/// treasury, paying the cranker `crank_tip_bp` of it
+pub fn handler(ctx: Context<CrankSkim>) -> Result<()> {
+    let casino = &mut ctx.accounts.casino;
+    let vault_balance = ctx.accounts.vault.lamports();
+    
+    casino.require_no_liquidity_pool()?;
+    
+    let required_balance = casino.required_vault_balance(Rent::get()?.minimum_balance(0))?;
+    let excess = vault_balance.saturating_sub(required_balance);
+    
+    msg!("Vault balance: {} lamports", vault_balance);
+    msg!("Required balance: {} lamports", required_balance);
+    
+    require!(excess > 0, CasinoError::NothingToSkim);
+    
+    let tip = (excess as u128)
+        .checked_mul(casino.reserve_policy.crank_tip_bp as u128)
+        .ok_or(CasinoError::Overflow)?
+        / BASIS_POINTS as u128;
+    let tip = tip as u64;
+    let amount = excess - tip;
+    
+    let casino_key = casino.key();
+    let vault_seeds: &[&[u8]] = &[VAULT_SEED, casino_key.as_ref(), &[casino.vault_bump]];
+    
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.treasury.to_account_info(),
+            },
+            &[vault_seeds],
+        ),
+        amount
+    )?;
+    
+    if tip > 0 {
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.cranker.to_account_info(),
+                },
+                &[vault_seeds],
+            ),
+            tip
+        )?;
+    }
+    
+    casino.total_treasury_skimmed = casino
+        .total_treasury_skimmed
+        .checked_add(amount)
+        .ok_or(CasinoError::Overflow)?;
+    
+    let vault_balance_after = ctx.accounts.vault.lamports();
+    let treasury_balance_after = ctx.accounts.treasury.lamports();
+    
+    msg!("Crank skimmed {} lamports to treasury, tip {} lamports", amount, tip);
+    msg!("Vault balance after: {} lamports", vault_balance_after);
+    
+    emit!(CrankSkimmed {
+        cranker: ctx.accounts.cranker.key(),
+        amount,
+        tip,
+        required_balance,
+        vault_balance_after,
+        treasury_balance_after,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct CrankSkimmed {
+    pub cranker: Pubkey,
+    pub amount: u64,
+    pub tip: u64,
+    pub required_balance: u64,
+    pub vault_balance_after: u64,
+    pub treasury_balance_after: u64,
+}
@@ -0,0 +1,76 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+#[instruction(signer: Pubkey)]
+pub struct CreateSessionKey<'info> {
+    #[account(
+        seeds = [CASINO_SEED],
+        bump,
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        init,
+        payer = player,
+        space = 8 + SessionKey::INIT_SPACE,
+        seeds = [SESSION_KEY_SEED, casino.key().as_ref(), player.key().as_ref(), signer.as_ref()],
+        bump
+    )]
+    pub session_key: Account<'info, SessionKey>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Authorize `signer` to bet from the player's balance until `expires_at`,
+/// up to `max_total_wager` lamports in total, on `allowed_games` only
+pub fn handler(
+    ctx: Context<CreateSessionKey>,
+    signer: Pubkey,
+    expires_at: i64,
+    max_total_wager: u64,
+    allowed_games: Vec<GameType>,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    
+    require!(
+        expires_at > now && expires_at <= now.saturating_add(MAX_SESSION_KEY_DURATION_SECONDS),
+        CasinoError::InvalidSessionKey
+    );
+    require!(max_total_wager > 0 && !allowed_games.is_empty(), CasinoError::InvalidSessionKey);
+    require!(signer != ctx.accounts.player.key(), CasinoError::InvalidSessionKey);
+    
+    let session_key = &mut ctx.accounts.session_key;
+    session_key.player = ctx.accounts.player.key();
+    session_key.signer = signer;
+    session_key.expires_at = expires_at;
+    session_key.max_total_wager = max_total_wager;
+    session_key.total_wagered = 0;
+    session_key.allowed_games = allowed_games.iter().fold(0, |mask, game| mask | game.bit());
+    session_key.bump = *ctx.bumps.get("session_key").unwrap();
+    
+    msg!("Session key {} authorized until {}", signer, expires_at);
+    
+    emit!(SessionKeyCreated {
+        player: session_key.player,
+        signer,
+        expires_at,
+        max_total_wager,
+        allowed_games: session_key.allowed_games,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct SessionKeyCreated {
+    pub player: Pubkey,
+    pub signer: Pubkey,
+    pub expires_at: i64,
+    pub max_total_wager: u64,
+    pub allowed_games: u8,
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+pub struct Deposit<'info> {
+    #[account(
+        mut,
+        seeds = [CASINO_SEED],
+        bump,
+        constraint = casino.is_operational() @ CasinoError::CasinoPaused,
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerBalance::INIT_SPACE,
+        seeds = [PLAYER_BALANCE_SEED, casino.key().as_ref(), player.key().as_ref()],
+        bump
+    )]
+    pub player_balance: Account<'info, PlayerBalance>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, casino.key().as_ref()],
+        bump = casino.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Move `amount` lamports into the vault, credited to the player's balance
+pub fn handler(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+    require!(amount > 0, CasinoError::InvalidDepositAmount);
+    
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.player.to_account_info(),
+                to: ctx.accounts.vault.to_account_info(),
+            },
+        ),
+        amount
+    )?;
+    
+    let player_balance = &mut ctx.accounts.player_balance;
+    player_balance.player = ctx.accounts.player.key();
+    player_balance.bump = *ctx.bumps.get("player_balance").unwrap();
+    player_balance.credit(amount)?;
+    player_balance.total_deposited = player_balance.total_deposited
+        .checked_add(amount)
+        .ok_or(CasinoError::Overflow)?;
+    
+    ctx.accounts.casino.add_player_balance(amount)?;
+    
+    msg!("Deposited {} lamports, balance {}", amount, player_balance.balance);
+    
+    emit!(BalanceDeposited {
+        player: player_balance.player,
+        amount,
+        balance: player_balance.balance,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct BalanceDeposited {
+    pub player: Pubkey,
+    pub amount: u64,
+    pub balance: u64,
+}
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use anchor_spl::token::TokenAccount;
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+pub struct DepositLiquidity<'info> {
+    #[account(
+        seeds = [CASINO_SEED],
+        bump,
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        mut,
+        seeds = [LIQUIDITY_POOL_SEED, casino.key().as_ref()],
+        bump = liquidity_pool.bump,
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+    
+    #[account(
+        init,
+        payer = depositor,
+        space = 8 + DepositRequest::INIT_SPACE,
+        seeds = [DEPOSIT_REQUEST_SEED, casino.key().as_ref(), depositor.key().as_ref()],
+        bump
+    )]
+    pub deposit_request: Account<'info, DepositRequest>,
+    
+    #[account(
+        token::mint = liquidity_pool.lp_mint,
+    )]
+    pub depositor_shares: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Queue a deposit of `amount` lamports. The lamports wait in the request
+/// until `process_deposit` prices them at a NAV snapshot taken after the
+/// cooldown, so a deposit cannot be timed around pending bets.
+pub fn handler(ctx: Context<DepositLiquidity>, amount: u64, min_shares: u64) -> Result<()> {
+    let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+    let deposit_request = &mut ctx.accounts.deposit_request;
+    let now = Clock::get()?.unix_timestamp;
+    
+    require!(amount > 0, CasinoError::InvalidLiquidityAmount);
+    
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.depositor.to_account_info(),
+                to: deposit_request.to_account_info(),
+            },
+        ),
+        amount
+    )?;
+    
+    deposit_request.owner = ctx.accounts.depositor.key();
+    deposit_request.owner_shares = ctx.accounts.depositor_shares.key();
+    deposit_request.amount = amount;
+    deposit_request.min_shares = min_shares;
+    deposit_request.requested_at = now;
+    deposit_request.bump = *ctx.bumps.get("deposit_request").unwrap();
+    
+    liquidity_pool.queued_deposits = liquidity_pool.queued_deposits
+        .checked_add(amount)
+        .ok_or(CasinoError::Overflow)?;
+    
+    msg!("Deposit of {} lamports queued", amount);
+    
+    emit!(LiquidityDepositRequested {
+        depositor: deposit_request.owner,
+        amount,
+        min_shares,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct LiquidityDepositRequested {
+    pub depositor: Pubkey,
+    pub amount: u64,
+    pub min_shares: u64,
+}
@@ -0,0 +1,166 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+pub struct DistributeTreasury<'info> {
+    #[account(
+        mut,
+        seeds = [CASINO_SEED],
+        bump,
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, casino.key().as_ref()],
+        bump = casino.treasury_bump
+    )]
+    pub treasury: SystemAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [REVENUE_SPLIT_SEED, casino.key().as_ref()],
+        bump = revenue_split.bump,
+    )]
+    pub revenue_split: Account<'info, RevenueSplit>,
+    
+    /// Treasurer role held by the signer
+    #[account(
+        seeds = [ROLE_SEED, casino.key().as_ref(), &Role::Treasurer.seed(), treasurer.key().as_ref()],
+        bump = treasurer_role.bump,
+    )]
+    pub treasurer_role: Account<'info, RoleAssignment>,
+    
+    pub treasurer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Pay `amount` out of the treasury split across the beneficiaries.
+/// `remaining_accounts` must be, in table order, each beneficiary's
+/// `TreasuryDestination` allowlist entry followed by its destination
+/// (writable). Rounding dust goes to the last beneficiary. Counts against the
+/// same per-epoch cap as `withdraw_treasury`.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, DistributeTreasury<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let casino = &mut ctx.accounts.casino;
+    let revenue_split = &mut ctx.accounts.revenue_split;
+    let clock = Clock::get()?;
+    
+    require!(amount > 0, CasinoError::InvalidWithdrawalAmount);
+    require!(
+        ctx.remaining_accounts.len() == 2 * revenue_split.beneficiaries.len(),
+        CasinoError::BeneficiaryMismatch
+    );
+    
+    // The Treasury must stay rent-exempt
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(0);
+    let required_balance = amount
+        .checked_add(rent_exempt_minimum)
+        .ok_or(CasinoError::Overflow)?;
+    require!(
+        ctx.accounts.treasury.lamports() >= required_balance,
+        CasinoError::InsufficientTreasuryFunds
+    );
+    
+    casino.record_treasury_withdrawal(amount, clock.epoch)?;
+    
+    let casino_key = casino.key();
+    let treasury_seeds: &[&[u8]] = &[TREASURY_SEED, casino_key.as_ref(), &[casino.treasury_bump]];
+    let last = revenue_split.beneficiaries.len() - 1;
+    let mut remaining = amount;
+    
+    for (i, (beneficiary, accounts)) in revenue_split.beneficiaries
+        .iter_mut()
+        .zip(ctx.remaining_accounts.chunks(2))
+        .enumerate()
+    {
+        let (allowlist_entry, destination) = (&accounts[0], &accounts[1]);
+        require!(
+            destination.key() == beneficiary.destination && destination.is_writable,
+            CasinoError::BeneficiaryMismatch
+        );
+        
+        // Same allowlist as `withdraw_treasury`: the entry must be the
+        // destination's PDA and still exist
+        let treasury_destination = Account::<TreasuryDestination>::try_from(allowlist_entry)
+            .map_err(|_| error!(CasinoError::BeneficiaryMismatch))?;
+        let expected_entry = Pubkey::create_program_address(
+            &[
+                TREASURY_DESTINATION_SEED,
+                casino_key.as_ref(),
+                beneficiary.destination.as_ref(),
+                &[treasury_destination.bump],
+            ],
+            &crate::ID,
+        )
+        .map_err(|_| error!(CasinoError::BeneficiaryMismatch))?;
+        require!(
+            allowlist_entry.key() == expected_entry
+                && treasury_destination.destination == beneficiary.destination,
+            CasinoError::BeneficiaryMismatch
+        );
+        
+        let share = if i == last {
+            remaining
+        } else {
+            ((amount as u128) * (beneficiary.share_bp as u128) / BASIS_POINTS as u128) as u64
+        };
+        remaining -= share;
+        
+        if share == 0 {
+            continue;
+        }
+        
+        // A transfer that leaves a new account below rent exemption would fail
+        require!(
+            destination.lamports()
+                .checked_add(share)
+                .ok_or(CasinoError::Overflow)? >= rent_exempt_minimum,
+            CasinoError::BeneficiaryBelowRentExempt
+        );
+        
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.treasury.to_account_info(),
+                    to: destination.clone(),
+                },
+                &[treasury_seeds],
+            ),
+            share
+        )?;
+        
+        beneficiary.total_received = beneficiary.total_received
+            .checked_add(share)
+            .ok_or(CasinoError::Overflow)?;
+        
+        msg!("Paid {} lamports to {}", share, beneficiary.destination);
+    }
+    
+    revenue_split.total_distributed = revenue_split.total_distributed
+        .checked_add(amount)
+        .ok_or(CasinoError::Overflow)?;
+    
+    emit!(TreasuryDistributed {
+        amount,
+        epoch: clock.epoch,
+        withdrawn_in_epoch: casino.treasury_withdrawn_in_epoch,
+        treasury_balance_after: ctx.accounts.treasury.lamports(),
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct TreasuryDistributed {
+    pub amount: u64,
+    pub epoch: u64,
+    pub withdrawn_in_epoch: u64,
+    pub treasury_balance_after: u64,
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+pub struct ExecuteChange<'info> {
+    #[account(
+        mut,
+        seeds = [CASINO_SEED],
+        bump,
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        mut,
+        seeds = [
+            PENDING_CHANGE_SEED,
+            casino.key().as_ref(),
+            &pending_change.id.to_le_bytes()
+        ],
+        bump = pending_change.bump,
+        close = proposer,
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+    
+    /// Vault PDA, read to validate bet limits against its balance
+    #[account(
+        seeds = [VAULT_SEED, casino.key().as_ref()],
+        bump = casino.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    /// Split table, required for `ConfigChange::RevenueSplit`
+    #[account(
+        mut,
+        seeds = [REVENUE_SPLIT_SEED, casino.key().as_ref()],
+        bump = revenue_split.bump,
+    )]
+    pub revenue_split: Option<Account<'info, RevenueSplit>>,
+    
+    /// Receives the pending change's rent
+    #[account(
+        mut,
+        address = pending_change.proposer @ CasinoError::Unauthorized,
+    )]
+    pub proposer: SystemAccount<'info>,
+    
+    /// Anyone can execute a change once its delay has elapsed
+    pub executor: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<ExecuteChange>) -> Result<()> {
+    let casino = &mut ctx.accounts.casino;
+    let pending_change = &ctx.accounts.pending_change;
+    let now = Clock::get()?.unix_timestamp;
+    
+    require!(now >= pending_change.eta, CasinoError::TimelockNotElapsed);
+    
+    match &pending_change.change {
+        ConfigChange::BetLimits { min_bet, max_bet } => {
+            CasinoConfig::validate_bet_limits(*min_bet, *max_bet, ctx.accounts.vault.lamports())?;
+            casino.min_bet = *min_bet;
+            casino.max_bet = *max_bet;
+            msg!("Bet limits set to {} - {} lamports", min_bet, max_bet);
+        },
+        ConfigChange::Authority { new_authority } => {
+            casino.pending_authority = Some(*new_authority);
+            msg!("Authority transfer proposed to {}", new_authority);
+        },
+        ConfigChange::RandomnessSource { source } => {
+            CasinoConfig::validate_randomness_source(*source)?;
+            casino.randomness_source = *source;
+            msg!("Randomness source updated");
+        },
+        ConfigChange::Oracle { oracle } => {
+            casino.oracle = *oracle;
+            msg!("Oracle set to {}", oracle);
+        },
+        ConfigChange::SwitchboardFunction { function } => {
+            casino.switchboard_function = *function;
+            msg!("Switchboard function updated");
+        },
+        ConfigChange::TimelockDelay { delay } => {
+            casino.timelock_delay = *delay;
+            msg!("Timelock delay set to {} seconds", delay);
+        },
+        ConfigChange::RevenueSplit { shares } => {
+            let revenue_split = ctx.accounts.revenue_split
+                .as_mut()
+                .ok_or(CasinoError::InvalidRevenueSplit)?;
+            revenue_split.set_shares(shares);
+            msg!("Revenue split set: {} beneficiaries", shares.len());
+        },
+        ConfigChange::TreasuryEpochCap { cap } => {
+            casino.treasury_epoch_cap = *cap;
+            msg!("Treasury epoch cap set to {} lamports", cap);
+        },
+        ConfigChange::ReservePolicy { policy } => {
+            casino.reserve_policy = *policy;
+            msg!("Reserve policy updated");
+        },
+        ConfigChange::ReferralShare { share_bp } => {
+            casino.referral_share_bp = *share_bp;
+            msg!("Referral share set to {} bp", share_bp);
+        },
+    }
+    
+    emit!(ChangeExecuted {
+        id: pending_change.id,
+        change: pending_change.change.clone(),
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct ChangeExecuted {
+    pub id: u64,
+    pub change: ConfigChange,
+}
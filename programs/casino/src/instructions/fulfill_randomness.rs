@@ -1,7 +1,12 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{self, Transfer};
+use anchor_lang::solana_program::sysvar;
 use crate::state::*;
 use crate::errors::CasinoError;
+use crate::outcome::{self, GameOutcome, OUTCOME_DERIVATION_VERSION};
+use crate::randomness::{commit_reveal, oracle, slot_hash};
+use crate::randomness::switchboard::SwitchboardRandomness;
+use crate::utils::load_if_initialized;
 
 #[derive(Accounts)]
 pub struct FulfillRandomness<'info> {
@@ -41,9 +46,93 @@ pub struct FulfillRandomness<'info> {
     )]
     pub player: SystemAccount<'info>,
     
-    /// Authority or Switchboard oracle (depending on mock vs real VRF)
+    /// Settler or player (any signer for settle_with_vrf / reveal_and_settle)
     pub caller: Signer<'info>,
     
+    /// Settler role held by the caller (not needed when the player settles)
+    #[account(
+        seeds = [ROLE_SEED, casino.key().as_ref(), &Role::Settler.seed(), caller.key().as_ref()],
+        bump = settler_role.bump,
+    )]
+    pub settler_role: Option<Account<'info, RoleAssignment>>,
+    
+    /// Switchboard randomness account bound to the session (Switchboard sessions only)
+    /// CHECK: Owner and discriminator verified when loaded
+    #[account(
+        constraint = session.randomness_request.switchboard_request == Some(randomness_account.key())
+            @ CasinoError::InvalidRandomnessAccount,
+    )]
+    pub randomness_account: Option<UncheckedAccount<'info>>,
+    
+    /// Seed chain the session's server seed comes from (commit-reveal sessions only)
+    #[account(
+        mut,
+        seeds = [SEED_CHAIN_SEED, casino.key().as_ref()],
+        bump = seed_chain.bump,
+    )]
+    pub seed_chain: Option<Account<'info, SeedChain>>,
+    
+    /// Token vault of the session's mint (token sessions only)
+    #[account(
+        mut,
+        seeds = [TOKEN_VAULT_SEED, casino.key().as_ref(), token_vault.mint.as_ref()],
+        bump = token_vault.bump,
+        constraint = session.mint == Some(token_vault.mint) @ CasinoError::MintMismatch,
+    )]
+    pub token_vault: Option<Account<'info, TokenVault>>,
+    
+    /// Balance the session was funded from (balance sessions only); wins are credited to it
+    #[account(
+        mut,
+        seeds = [PLAYER_BALANCE_SEED, casino.key().as_ref(), session.player.as_ref()],
+        bump = player_balance.bump,
+    )]
+    pub player_balance: Option<Account<'info, PlayerBalance>>,
+    
+    /// Player profile, updated once it exists (SOL sessions only)
+    /// CHECK: Address verified via seeds; deserialized in the handler if initialized
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, casino.key().as_ref(), session.player.as_ref()],
+        bump,
+    )]
+    pub player_profile: UncheckedAccount<'info>,
+    
+    /// Profile of the player's referrer (required when the player has one)
+    #[account(
+        mut,
+        seeds = [PLAYER_SEED, casino.key().as_ref(), referrer_profile.authority.as_ref()],
+        bump = referrer_profile.bump,
+    )]
+    pub referrer_profile: Option<Account<'info, Player>>,
+    
+    /// VIP tier table, applied once it exists
+    /// CHECK: Address verified via seeds; deserialized in the handler if initialized
+    #[account(
+        seeds = [VIP_CONFIG_SEED, casino.key().as_ref()],
+        bump,
+    )]
+    pub vip_config: UncheckedAccount<'info>,
+    
+    /// Player's limits; SOL payouts reduce their net loss once it exists
+    /// CHECK: Address verified via seeds; deserialized in the handler if initialized
+    #[account(
+        mut,
+        seeds = [PLAYER_LIMITS_SEED, casino.key().as_ref(), session.player.as_ref()],
+        bump,
+    )]
+    pub player_limits: UncheckedAccount<'info>,
+    
+    /// Instructions sysvar, used to find the oracle's Ed25519 signature
+    /// CHECK: Address verified against the sysvar ID
+    #[account(address = sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+    
+    /// SlotHashes sysvar, read by the slot-hash source
+    /// CHECK: Address verified against the sysvar ID
+    #[account(address = sysvar::slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+    
     pub system_program: Program<'info, System>,
     pub clock: Sysvar<'info, Clock>,
 }
@@ -51,48 +140,135 @@ pub struct FulfillRandomness<'info> {
 pub fn handler(
     ctx: Context<FulfillRandomness>,
     random_value: [u8; 32],
+    server_seed: Option<String>,
 ) -> Result<()> {
-    let casino = &mut ctx.accounts.casino;
-    let session = &mut ctx.accounts.session;
-    let clock = &ctx.accounts.clock;
+    let session = &ctx.accounts.session;
+    
+    // Verify caller is a settler OR the player
+    let is_settler = ctx.accounts.settler_role.is_some();
+    let is_player = ctx.accounts.caller.key() == session.player;
+    require!(is_settler || is_player, CasinoError::Unauthorized);
+    
+    let request = &session.randomness_request;
+    if request.source == RandomnessSource::OracleSigned && request.oracle_signature_hash.is_none() {
+        return lock_oracle_signature(ctx.accounts);
+    }
+    
+    let random_value = verified_randomness(ctx.accounts, random_value, server_seed)?;
+    
+    settle(ctx.accounts, random_value)
+}
+
+/// Derive the session's random value through the verification path of the
+/// randomness source it was placed with.
+///
+/// `mock_value` is only used by the mock source and `server_seed` only by
+/// commit-reveal; other sources read their proof from the accounts.
+pub(crate) fn verified_randomness(
+    accounts: &mut FulfillRandomness,
+    mock_value: [u8; 32],
+    server_seed: Option<String>,
+) -> Result<[u8; 32]> {
+    let session = &accounts.session;
+    let request = &session.randomness_request;
+    
+    match request.source {
+        // Localnet: the caller supplies the random value directly
+        RandomnessSource::Mock => {
+            CasinoConfig::validate_randomness_source(RandomnessSource::Mock)?;
+            Ok(mock_value)
+        },
+        RandomnessSource::OracleSigned => {
+            let signature_hash = request.oracle_signature_hash
+                .as_ref()
+                .ok_or(CasinoError::RandomnessNotRevealed)?;
+            oracle::randomness(
+                &accounts.slot_hashes.to_account_info(),
+                signature_hash,
+                request.commit_slot,
+            )
+        },
+        RandomnessSource::Switchboard => {
+            let randomness_account = accounts.randomness_account
+                .as_ref()
+                .ok_or(CasinoError::InvalidRandomnessAccount)?;
+            let randomness = SwitchboardRandomness::load(&randomness_account.to_account_info())?;
+            msg!("Switchboard randomness revealed at slot {}", randomness.reveal_slot);
+            randomness.revealed_value(request.commit_slot, &session.key())
+        },
+        RandomnessSource::CommitReveal => {
+            let server_seed = server_seed.ok_or(CasinoError::InvalidServerSeed)?;
+            // The chain must be passed so a revealed seed can never stay the live commitment
+            let seed_chain = accounts.seed_chain
+                .as_mut()
+                .ok_or(CasinoError::InvalidServerSeed)?;
+            commit_reveal::reveal(seed_chain, request, session.game_id, &server_seed)
+        },
+        RandomnessSource::SlotHash => slot_hash::randomness(
+            &accounts.slot_hashes.to_account_info(),
+            session.created_slot,
+            &session.key(),
+        ),
+    }
+}
+
+/// First step of an oracle-signed settlement: verify the oracle's signature and
+/// lock its hash into the session. The outcome is only derived by the next call,
+/// from the signature and the hash of this slot, so the oracle cannot grind
+/// signatures for a favourable result. Either the settler or the player can
+/// make that call, within the 512-slot SlotHashes window.
+fn lock_oracle_signature(accounts: &mut FulfillRandomness) -> Result<()> {
+    let session = &mut accounts.session;
+    let clock = &accounts.clock;
     
-    // Check session not expired
     require!(
         !session.is_expired(clock.unix_timestamp),
         CasinoError::SessionExpired
     );
     
-    // Verify caller is authority OR player
-    let is_authority = ctx.accounts.caller.key() == casino.authority;
-    let is_player = ctx.accounts.caller.key() == session.player;
-    require!(is_authority || is_player, CasinoError::Unauthorized);
+    // The key in force when the bet was placed, not a later replacement
+    let request = &session.randomness_request;
+    let oracle_key = request.oracle.ok_or(CasinoError::InvalidRandomnessCallback)?;
+    let message = oracle::oracle_message(&session.key(), session.game_id, session.created_slot);
+    let signature_hash = oracle::verified_signature_hash(
+        &accounts.instructions_sysvar.to_account_info(),
+        &oracle_key,
+        &message,
+    )?;
     
-    #[cfg(feature = "mock-vrf")]
-    require!(session.randomness_request.is_mock, CasinoError::InvalidRandomnessCallback);
+    let request = &mut session.randomness_request;
+    request.oracle_signature_hash = Some(signature_hash);
+    request.commit_slot = clock.slot;
     
-    #[cfg(not(feature = "mock-vrf"))]
-    require!(!session.randomness_request.is_mock, CasinoError::MockVRFNotAllowed);
+    msg!("Oracle signature locked in at slot {}; settle after this slot", clock.slot);
     
-    let (outcome, is_win, payout_multiplier) = match session.game_type {
-        GameType::CoinFlip => {
-            let o = if random_value[0] < 128 { 0 } else { 1 };
-            let w = o == session.choice;
-            (o, w, if w { PAYOUT_MULTIPLIER_BP } else { 0 })
-        },
-        GameType::Dice => {
-            let r = (random_value[0] % 6) + 1 + (random_value[1] % 6) + 1;
-            let w = r == session.choice;
-            (r, w, if w { 50000 } else { 0 })
-        },
-        GameType::Slots => {
-            let r1 = random_value[0] % 10;
-            let r2 = random_value[1] % 10;
-            let r3 = random_value[2] % 10;
-            let o = ((r1 as u16 * 100 + r2 as u16 * 10 + r3 as u16) % 256) as u8;
-            let w = r1 == r2 && r2 == r3;
-            (o, w, if w { 100000 } else { 0 })
-        },
-    };
+    Ok(())
+}
+
+/// Resolve the session from its random value and, if the player is the caller,
+/// pay a win out immediately. Shared by every settlement instruction.
+pub(crate) fn settle(accounts: &mut FulfillRandomness, random_value: [u8; 32]) -> Result<()> {
+    let casino = &mut accounts.casino;
+    let session = &mut accounts.session;
+    let clock = &accounts.clock;
+    
+    // Check session not expired
+    require!(
+        !session.is_expired(clock.unix_timestamp),
+        CasinoError::SessionExpired
+    );
+    
+    let GameOutcome { outcome, is_win, payout_multiplier_bp: payout_multiplier } =
+        outcome::resolve(session.game_type, session.choice, &random_value);
+    
+    // Free the reservation in the vault holding the bet
+    match session.mint {
+        Some(_) => accounts.token_vault
+            .as_mut()
+            .ok_or(CasinoError::MintMismatch)?
+            .release_liability(session.max_payout)?,
+        None => casino.release_liability(session.max_payout)?,
+    }
     
     msg!("Random value (first bytes): {:?}", &random_value[0..3]);
     msg!("Outcome: {}", outcome);
@@ -113,20 +289,38 @@ pub fn handler(
     let should_transfer_payout = is_win && payout > 0;
     let payout_claimed: bool;
     
-    if should_transfer_payout {
+    if should_transfer_payout && session.mint.is_some() {
+        // Token wins are always claimed through `claim_token_payout`
+        accounts.token_vault
+            .as_mut()
+            .ok_or(CasinoError::MintMismatch)?
+            .add_unclaimed_payout(payout)?;
+        payout_claimed = false;
+    } else if should_transfer_payout && session.from_balance {
+        // Balance bets are credited straight back, whoever settles
+        accounts.player_balance
+            .as_mut()
+            .ok_or(CasinoError::InsufficientPlayerBalance)?
+            .credit(payout)?;
+        casino.add_player_balance(payout)?;
+        casino.total_payouts = casino.total_payouts
+            .checked_add(payout)
+            .ok_or(CasinoError::Overflow)?;
+        payout_claimed = true;
+    } else if should_transfer_payout {
         // For WINS: Only transfer payout if PLAYER is the caller
-        let is_player = ctx.accounts.caller.key() == session.player;
+        let is_player = accounts.caller.key() == session.player;
         
         if is_player {
             let casino_key = casino.key();
             let vault_seeds = &[VAULT_SEED, casino_key.as_ref(), &[casino.vault_bump]];
             let signer_seeds = &[&vault_seeds[..]];
             let transfer_cpi = Transfer {
-                from: ctx.accounts.vault.to_account_info(),
-                to: ctx.accounts.player.to_account_info(),
+                from: accounts.vault.to_account_info(),
+                to: accounts.player.to_account_info(),
             };
             let cpi_ctx = CpiContext::new_with_signer(
-                ctx.accounts.system_program.to_account_info(),
+                accounts.system_program.to_account_info(),
                 transfer_cpi,
                 signer_seeds,
             );
@@ -136,6 +330,7 @@ pub fn handler(
                 .ok_or(CasinoError::Overflow)?;
             payout_claimed = true;
         } else {
+            casino.add_unclaimed_payout(payout)?;
             payout_claimed = false;
         }
     } else {
@@ -144,6 +339,42 @@ pub fn handler(
         payout_claimed = true; // Nothing to claim
     }
     
+    // Limits, stats, rakeback and referral rewards are kept in lamports, so
+    // token sessions are not counted. The accounts are always passed at their
+    // PDA addresses and processed whenever they exist, so no caller can skip them.
+    if session.mint.is_none() {
+        let player_limits_info = accounts.player_limits.to_account_info();
+        if let Some(mut player_limits) = load_if_initialized::<PlayerLimits>(&player_limits_info)? {
+            if payout > 0 {
+                player_limits.record_payout(payout, clock.unix_timestamp)?;
+            }
+            player_limits.exit(&crate::ID)?;
+        }
+        
+        let player_profile_info = accounts.player_profile.to_account_info();
+        if let Some(mut player_profile) = load_if_initialized::<Player>(&player_profile_info)? {
+            player_profile.update_after_game(session.bet_amount, payout, is_win, clock.unix_timestamp)?;
+            
+            let vip_config_info = accounts.vip_config.to_account_info();
+            if let Some(vip_config) = load_if_initialized::<VipConfig>(&vip_config_info)? {
+                let rakeback = player_profile.apply_vip(&vip_config, session.game_type, session.choice, session.bet_amount)?;
+                casino.add_rakeback(rakeback)?;
+            }
+            
+            if let Some(referrer) = player_profile.referrer {
+                let referrer_profile = accounts.referrer_profile
+                    .as_mut()
+                    .filter(|profile| profile.authority == referrer)
+                    .ok_or(CasinoError::InvalidReferrer)?;
+                let reward = casino.referral_reward(session.game_type, session.choice, session.bet_amount)?;
+                referrer_profile.accrue_referral_reward(reward)?;
+                casino.add_referral_reward(reward)?;
+            }
+            
+            player_profile.exit(&crate::ID)?;
+        }
+    }
+    
     // Update session with result
     session.status = SessionStatus::Resolved;
     session.resolved_at = Some(clock.unix_timestamp);
@@ -152,14 +383,55 @@ pub fn handler(
         is_win,
         payout,
         payout_claimed,
+        derivation_version: OUTCOME_DERIVATION_VERSION,
     });
     
     msg!("Session resolved!");
     msg!("Final payout: {} lamports", payout);
     
-    // NOTE: We intentionally keep the session account open so the frontend can poll and display
-    // the result without requiring the player to sign a separate transaction.
+    emit!(SessionSettled {
+        session: session.key(),
+        player: session.player,
+        game_id: session.game_id,
+        game_type: session.game_type,
+        mint: session.mint,
+        bet_amount: session.bet_amount,
+        choice: session.choice,
+        random_value,
+        outcome,
+        is_win,
+        payout,
+        payout_claimed,
+        derivation_version: OUTCOME_DERIVATION_VERSION,
+    });
+    
+    // Sessions stay open by default so the frontend can poll the result. With
+    // `auto_close_sessions` the event above is the record and the rent goes
+    // back to the player, unless a payout still has to be claimed.
+    if casino.auto_close_sessions && payout_claimed {
+        accounts.session.close(accounts.player.to_account_info())?;
+        msg!("Session closed");
+    }
     
     Ok(())
 }
 
+/// Full result of a settled session, so it can be archived off-chain before
+/// the session account is closed
+#[event]
+pub struct SessionSettled {
+    pub session: Pubkey,
+    pub player: Pubkey,
+    pub game_id: u64,
+    pub game_type: GameType,
+    pub mint: Option<Pubkey>,
+    pub bet_amount: u64,
+    pub choice: u8,
+    pub random_value: [u8; 32],
+    pub outcome: u8,
+    pub is_win: bool,
+    pub payout: u64,
+    pub payout_claimed: bool,
+    pub derivation_version: u8,
+}
+
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+#[instruction(role: Role, member: Pubkey)]
+pub struct GrantRole<'info> {
+    #[account(
+        seeds = [CASINO_SEED],
+        bump,
+        has_one = authority @ CasinoError::Unauthorized
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + RoleAssignment::INIT_SPACE,
+        seeds = [ROLE_SEED, casino.key().as_ref(), &role.seed(), member.as_ref()],
+        bump
+    )]
+    pub role_assignment: Account<'info, RoleAssignment>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<GrantRole>, role: Role, member: Pubkey) -> Result<()> {
+    let role_assignment = &mut ctx.accounts.role_assignment;
+    
+    role_assignment.role = role;
+    role_assignment.member = member;
+    role_assignment.granted_at = Clock::get()?.unix_timestamp;
+    role_assignment.bump = *ctx.bumps.get("role_assignment").unwrap();
+    
+    msg!("Role granted to {}", member);
+    
+    emit!(RoleGranted { role, member });
+    
+    Ok(())
+}
+
+#[event]
+pub struct RoleGranted {
+    pub role: Role,
+    pub member: Pubkey,
+}
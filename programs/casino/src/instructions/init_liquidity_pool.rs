@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+pub struct InitLiquidityPool<'info> {
+    #[account(
+        mut,
+        seeds = [CASINO_SEED],
+        bump,
+        has_one = authority @ CasinoError::Unauthorized
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + LiquidityPool::INIT_SPACE,
+        seeds = [LIQUIDITY_POOL_SEED, casino.key().as_ref()],
+        bump
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+    
+    #[account(
+        init,
+        payer = authority,
+        seeds = [LP_MINT_SEED, casino.key().as_ref()],
+        bump,
+        mint::decimals = LP_MINT_DECIMALS,
+        mint::authority = liquidity_pool,
+    )]
+    pub lp_mint: Account<'info, Mint>,
+    
+    /// Holds the shares of queued withdrawals
+    #[account(
+        init,
+        payer = authority,
+        seeds = [LP_ESCROW_SEED, casino.key().as_ref()],
+        bump,
+        token::mint = lp_mint,
+        token::authority = liquidity_pool,
+    )]
+    pub lp_escrow: Account<'info, TokenAccount>,
+    
+    #[account(
+        seeds = [VAULT_SEED, casino.key().as_ref()],
+        bump = casino.vault_bump
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    /// Receives shares for the house capital already in the vault
+    #[account(
+        mut,
+        token::mint = lp_mint,
+    )]
+    pub authority_shares: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Open the vault to outside liquidity. The capital already in the vault is
+/// minted to the authority 1:1 as the first shares.
+pub fn handler(ctx: Context<InitLiquidityPool>) -> Result<()> {
+    let casino = &mut ctx.accounts.casino;
+    let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+    
+    let nav = casino.net_asset_value(
+        ctx.accounts.vault.lamports(),
+        ctx.accounts.rent.minimum_balance(0),
+    )?;
+    
+    liquidity_pool.lp_mint = ctx.accounts.lp_mint.key();
+    liquidity_pool.total_deposited = 0;
+    liquidity_pool.total_withdrawn = 0;
+    liquidity_pool.bump = *ctx.bumps.get("liquidity_pool").unwrap();
+    liquidity_pool.mint_bump = *ctx.bumps.get("lp_mint").unwrap();
+    liquidity_pool.escrow_bump = *ctx.bumps.get("lp_escrow").unwrap();
+    liquidity_pool.next_request_id = 0;
+    liquidity_pool.queue_head = 0;
+    liquidity_pool.queued_shares = 0;
+    liquidity_pool.snapshot_epoch = 0;
+    liquidity_pool.snapshot_nav = 0;
+    liquidity_pool.snapshot_supply = 0;
+    liquidity_pool.snapshot_taken_at = 0;
+    liquidity_pool.queued_deposits = 0;
+    
+    casino.lp_mint = Some(liquidity_pool.lp_mint);
+    
+    if nav > 0 {
+        let casino_key = casino.key();
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.lp_mint.to_account_info(),
+                    to: ctx.accounts.authority_shares.to_account_info(),
+                    authority: liquidity_pool.to_account_info(),
+                },
+                &[&[LIQUIDITY_POOL_SEED, casino_key.as_ref(), &[liquidity_pool.bump]]],
+            ),
+            nav
+        )?;
+    }
+    
+    msg!("Liquidity pool initialized, LP mint: {}", liquidity_pool.lp_mint);
+    msg!("House shares minted: {}", nav);
+    
+    emit!(LiquidityPoolInitialized {
+        lp_mint: liquidity_pool.lp_mint,
+        initial_shares: nav,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct LiquidityPoolInitialized {
+    pub lp_mint: Pubkey,
+    pub initial_shares: u64,
+}
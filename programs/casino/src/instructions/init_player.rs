@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+pub struct InitPlayer<'info> {
+    #[account(
+        seeds = [CASINO_SEED],
+        bump,
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Player::INIT_SPACE,
+        seeds = [PLAYER_SEED, casino.key().as_ref(), authority.key().as_ref()],
+        bump
+    )]
+    pub player: Account<'info, Player>,
+    
+    /// Profile of the referrer (only when registering one)
+    #[account(
+        seeds = [PLAYER_SEED, casino.key().as_ref(), referrer_profile.authority.as_ref()],
+        bump = referrer_profile.bump,
+    )]
+    pub referrer_profile: Option<Account<'info, Player>>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the wallet's `Player` profile, optionally registering a referrer.
+/// The referrer can never change afterwards.
+pub fn handler(ctx: Context<InitPlayer>, referrer: Option<Pubkey>) -> Result<()> {
+    let authority = ctx.accounts.authority.key();
+    
+    if let Some(referrer) = referrer {
+        require!(referrer != authority, CasinoError::SelfReferral);
+        // The referrer must already have a profile, and this one is only being
+        // created now, so nobody can be referred by it yet and no cycle can form
+        let referrer_profile = ctx.accounts.referrer_profile
+            .as_ref()
+            .ok_or(CasinoError::InvalidReferrer)?;
+        require!(referrer_profile.authority == referrer, CasinoError::InvalidReferrer);
+        require!(referrer_profile.referrer != Some(authority), CasinoError::InvalidReferrer);
+    }
+    
+    let player = &mut ctx.accounts.player;
+    player.authority = authority;
+    player.stats = PlayerStats::default();
+    player.treasury_contribution = 0;
+    player.total_deposited = 0;
+    player.total_withdrawn = 0;
+    player.vip_tier = 0;
+    player.rakeback = 0;
+    player.rakeback_claimed = 0;
+    player.referrer = referrer;
+    player.referral_rewards = 0;
+    player.referral_rewards_claimed = 0;
+    player.last_game_at = None;
+    player.created_at = Clock::get()?.unix_timestamp;
+    player.bump = *ctx.bumps.get("player").unwrap();
+    
+    msg!("Player profile created for {}", player.authority);
+    
+    Ok(())
+}
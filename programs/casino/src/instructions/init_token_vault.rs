@@ -0,0 +1,122 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::*;
+use crate::errors::CasinoError;
+use crate::token_extensions;
+
+#[derive(Accounts)]
+pub struct InitTokenVault<'info> {
+    #[account(
+        seeds = [CASINO_SEED],
+        bump,
+        has_one = authority @ CasinoError::Unauthorized
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + TokenVault::INIT_SPACE,
+        seeds = [TOKEN_VAULT_SEED, casino.key().as_ref(), mint.key().as_ref()],
+        bump
+    )]
+    pub token_vault: Account<'info, TokenVault>,
+    
+    #[account(
+        mint::token_program = token_program,
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = authority,
+        associated_token::mint = mint,
+        associated_token::authority = token_vault,
+    )]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Funds the initial bankroll
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = authority,
+    )]
+    pub authority_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub token_program: Interface<'info, TokenInterface>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Open betting in `mint` with its own bankroll and bet limits.
+/// `transfer_hook_program` allowlists the mint's Token-2022 transfer hook, if any.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, InitTokenVault<'info>>,
+    min_bet: u64,
+    max_bet: u64,
+    initial_vault_amount: u64,
+    transfer_hook_program: Option<Pubkey>,
+) -> Result<()> {
+    token_extensions::validate_mint(&ctx.accounts.mint.to_account_info(), transfer_hook_program)?;
+    
+    token_extensions::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.authority_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.vault_token_account.to_account_info(),
+                authority: ctx.accounts.authority.to_account_info(),
+            },
+        ).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        initial_vault_amount,
+        ctx.accounts.mint.decimals,
+    )?;
+    
+    // Transfer fees are withheld from what arrives
+    ctx.accounts.vault_token_account.reload()?;
+    let bankroll = ctx.accounts.vault_token_account.amount;
+    CasinoConfig::validate_bet_limits(min_bet, max_bet, bankroll)?;
+    
+    let token_vault = &mut ctx.accounts.token_vault;
+    token_vault.mint = ctx.accounts.mint.key();
+    token_vault.vault_token_account = ctx.accounts.vault_token_account.key();
+    token_vault.decimals = ctx.accounts.mint.decimals;
+    token_vault.token_program = ctx.accounts.token_program.key();
+    token_vault.transfer_hook_program = transfer_hook_program;
+    token_vault.min_bet = min_bet;
+    token_vault.max_bet = max_bet;
+    token_vault.is_active = true;
+    token_vault.pending_liability = 0;
+    token_vault.unclaimed_payouts = 0;
+    token_vault.total_volume = 0;
+    token_vault.total_payouts = 0;
+    token_vault.bump = *ctx.bumps.get("token_vault").unwrap();
+    
+    msg!("Token vault initialized for mint {}", token_vault.mint);
+    msg!("Token program: {}", token_vault.token_program);
+    msg!("Min bet: {}, max bet: {}", min_bet, max_bet);
+    
+    emit!(TokenVaultInitialized {
+        mint: token_vault.mint,
+        vault_token_account: token_vault.vault_token_account,
+        min_bet,
+        max_bet,
+        initial_vault_amount: bankroll,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct TokenVaultInitialized {
+    pub mint: Pubkey,
+    pub vault_token_account: Pubkey,
+    pub min_bet: u64,
+    pub max_bet: u64,
+    pub initial_vault_amount: u64,
+}
@@ -1,7 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program;
 use crate::state::*;
-use crate::errors::CasinoError;
 
 #[derive(Accounts)]
 pub struct Initialize<'info> {
@@ -39,19 +38,16 @@ pub fn handler(
     min_bet: u64,
     max_bet: u64,
     initial_vault_amount: u64,
+    oracle: Pubkey,
 ) -> Result<()> {
-    require!(min_bet > 0, CasinoError::InvalidBetAmount);
-    require!(max_bet >= min_bet, CasinoError::InvalidBetAmount);
-    require!(
-        max_bet <= initial_vault_amount / 2,
-        CasinoError::InvalidBetAmount
-    );
+    CasinoConfig::validate_bet_limits(min_bet, max_bet, initial_vault_amount)?;
     
     let casino = &mut ctx.accounts.casino;
     let vault_bump = *ctx.bumps.get("vault").unwrap();
     let treasury_bump = *ctx.bumps.get("treasury").unwrap();
     
     casino.authority = ctx.accounts.authority.key();
+    casino.pending_authority = None;
     casino.vault_bump = vault_bump;
     casino.treasury_bump = treasury_bump;
     casino.min_bet = min_bet;
@@ -60,8 +56,29 @@ pub fn handler(
     casino.total_volume = 0;
     casino.total_payouts = 0;
     casino.total_treasury_skimmed = 0;
+    casino.pending_liability = 0;
+    casino.unclaimed_payouts = 0;
+    casino.player_balances = 0;
+    casino.timelock_delay = 0;
+    casino.next_change_id = 0;
+    casino.treasury_epoch_cap = 0;
+    casino.treasury_epoch = 0;
+    casino.treasury_withdrawn_in_epoch = 0;
+    casino.total_treasury_withdrawn = 0;
+    casino.reserve_policy = ReservePolicy::default();
+    casino.lp_mint = None;
+    casino.referral_share_bp = DEFAULT_REFERRAL_SHARE_BP;
+    casino.referral_rewards = 0;
+    casino.rakeback = 0;
+    casino.auto_close_sessions = false;
     casino.is_active = true;
     casino.switchboard_function = None;
+    casino.oracle = oracle;
+    casino.randomness_source = if cfg!(feature = "mock-vrf") {
+        RandomnessSource::Mock
+    } else {
+        RandomnessSource::OracleSigned
+    };
     
     if initial_vault_amount > 0 {
         let transfer_cpi = system_program::Transfer {
@@ -79,6 +96,7 @@ pub fn handler(
     msg!("Authority: {}", casino.authority);
     msg!("Min bet: {} lamports", casino.min_bet);
     msg!("Max bet: {} lamports", casino.max_bet);
+    msg!("Oracle: {}", casino.oracle);
     msg!("Initial vault balance: {} lamports", initial_vault_amount);
     
     Ok(())
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, CreateAccount};
+use crate::errors::CasinoError;
+use crate::randomness::switchboard::{SwitchboardRandomness, RANDOMNESS_ACCOUNT_LEN};
+
+#[derive(Accounts)]
+pub struct MockSwitchboardRandomness<'info> {
+    /// Fake randomness account, created on the first call (it must sign then)
+    /// CHECK: Only ever written in `mock-vrf` builds, in Switchboard's layout
+    #[account(mut)]
+    pub randomness_account: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Localnet stand-in for Switchboard On-Demand (`mock-vrf` builds only).
+/// `None` commits the account to the previous slot's slothash; `Some(value)`
+/// reveals `value` in the current slot.
+pub fn handler(ctx: Context<MockSwitchboardRandomness>, reveal: Option<[u8; 32]>) -> Result<()> {
+    require!(cfg!(feature = "mock-vrf"), CasinoError::MockVRFNotAllowed);
+    
+    let randomness_account = &ctx.accounts.randomness_account;
+    let slot = Clock::get()?.slot;
+    
+    if randomness_account.data_is_empty() {
+        system_program::create_account(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                CreateAccount {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: randomness_account.to_account_info(),
+                },
+            ),
+            Rent::get()?.minimum_balance(RANDOMNESS_ACCOUNT_LEN),
+            RANDOMNESS_ACCOUNT_LEN as u64,
+            &crate::ID,
+        )?;
+    }
+    
+    let randomness = match reveal {
+        None => SwitchboardRandomness {
+            seed_slot: slot.saturating_sub(1),
+            reveal_slot: 0,
+            value: [0u8; 32],
+        },
+        Some(value) => SwitchboardRandomness {
+            reveal_slot: slot,
+            value,
+            ..SwitchboardRandomness::load(&randomness_account.to_account_info())?
+        },
+    };
+    randomness.store(&mut randomness_account.try_borrow_mut_data()?)?;
+    
+    msg!(
+        "Mock randomness seed slot {}, reveal slot {}",
+        randomness.seed_slot,
+        randomness.reveal_slot
+    );
+    
+    Ok(())
+}
@@ -1,9 +1,55 @@
+#![allow(ambiguous_glob_reexports)]
+
 pub mod initialize;
 pub mod place_bet;
 pub mod fulfill_randomness;
 pub mod claim_payout;
 pub mod refund_expired;
 pub mod skim_excess_to_treasury;
+pub mod settle_with_vrf;
+pub mod commit_seed_chain;
+pub mod reveal_and_settle;
+pub mod set_randomness_source;
+pub mod update_config;
+pub mod propose_authority;
+pub mod accept_authority;
+pub mod grant_role;
+pub mod revoke_role;
+pub mod set_active;
+pub mod queue_change;
+pub mod execute_change;
+pub mod cancel_change;
+pub mod add_treasury_destination;
+pub mod remove_treasury_destination;
+pub mod withdraw_treasury;
+pub mod crank_skim;
+pub mod set_revenue_split;
+pub mod distribute_treasury;
+pub mod init_liquidity_pool;
+pub mod deposit_liquidity;
+pub mod process_deposit;
+pub mod request_withdrawal;
+pub mod process_withdrawal;
+pub mod cancel_withdrawal;
+pub mod init_token_vault;
+pub mod update_token_vault;
+pub mod place_token_bet;
+pub mod claim_token_payout;
+pub mod refund_expired_token;
+pub mod deposit;
+pub mod withdraw;
+pub mod place_bet_from_balance;
+pub mod create_session_key;
+pub mod revoke_session_key;
+pub mod init_player;
+pub mod claim_referral_rewards;
+pub mod set_vip_tiers;
+pub mod claim_rakeback;
+pub mod set_player_limits;
+pub mod self_exclude;
+pub mod close_session;
+pub mod mock_switchboard_randomness;
+pub mod withdraw_token_vault;
 
 pub use initialize::*;
 pub use place_bet::*;
@@ -11,3 +57,45 @@ pub use fulfill_randomness::*;
 pub use claim_payout::*;
 pub use refund_expired::*;
 pub use skim_excess_to_treasury::*;
+pub use commit_seed_chain::*;
+pub use set_randomness_source::*;
+pub use update_config::*;
+pub use propose_authority::*;
+pub use accept_authority::*;
+pub use grant_role::*;
+pub use revoke_role::*;
+pub use set_active::*;
+pub use queue_change::*;
+pub use execute_change::*;
+pub use cancel_change::*;
+pub use add_treasury_destination::*;
+pub use remove_treasury_destination::*;
+pub use withdraw_treasury::*;
+pub use crank_skim::*;
+pub use set_revenue_split::*;
+pub use distribute_treasury::*;
+pub use init_liquidity_pool::*;
+pub use deposit_liquidity::*;
+pub use process_deposit::*;
+pub use request_withdrawal::*;
+pub use process_withdrawal::*;
+pub use cancel_withdrawal::*;
+pub use init_token_vault::*;
+pub use update_token_vault::*;
+pub use place_token_bet::*;
+pub use claim_token_payout::*;
+pub use refund_expired_token::*;
+pub use deposit::*;
+pub use withdraw::*;
+pub use place_bet_from_balance::*;
+pub use create_session_key::*;
+pub use revoke_session_key::*;
+pub use init_player::*;
+pub use claim_referral_rewards::*;
+pub use set_vip_tiers::*;
+pub use claim_rakeback::*;
+pub use set_player_limits::*;
+pub use self_exclude::*;
+pub use close_session::*;
+pub use mock_switchboard_randomness::*;
+pub use withdraw_token_vault::*;
@@ -2,6 +2,8 @@ use anchor_lang::prelude::*;
 use anchor_lang::system_program::{self, Transfer};
 use crate::state::*;
 use crate::errors::CasinoError;
+use crate::randomness::switchboard::SwitchboardRandomness;
+use crate::utils::load_if_initialized;
 
 #[derive(Accounts)]
 #[instruction(choice: u8, bet_amount: u64)]
@@ -40,6 +42,27 @@ pub struct PlaceBet<'info> {
     #[account(mut)]
     pub player: Signer<'info>,
     
+    /// Player's `PlayerLimits` PDA, enforced once it exists
+    /// CHECK: Address verified via seeds; deserialized in the handler if initialized
+    #[account(
+        mut,
+        seeds = [PLAYER_LIMITS_SEED, casino.key().as_ref(), player.key().as_ref()],
+        bump,
+    )]
+    pub player_limits: UncheckedAccount<'info>,
+    
+    /// Switchboard On-Demand randomness account committed within the last few
+    /// slots (Switchboard source only)
+    /// CHECK: Owner, discriminator and freshness verified in the handler
+    pub randomness_account: Option<UncheckedAccount<'info>>,
+    
+    /// Server-seed chain (commit-reveal source only)
+    #[account(
+        seeds = [SEED_CHAIN_SEED, casino.key().as_ref()],
+        bump = seed_chain.bump,
+    )]
+    pub seed_chain: Option<Account<'info, SeedChain>>,
+    
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
     pub clock: Sysvar<'info, Clock>,
@@ -50,26 +73,17 @@ pub fn handler(
     game_type: GameType,
     choice: u8,
     bet_amount: u64,
+    client_seed: Option<String>,
 ) -> Result<()> {
     let casino = &mut ctx.accounts.casino;
     let session = &mut ctx.accounts.session;
     let clock = &ctx.accounts.clock;
     
-    // Validate choice based on game type
-    match game_type {
-        GameType::CoinFlip => {
-            require!(choice == 0 || choice == 1, CasinoError::InvalidChoice);
-        },
-        GameType::Dice => {
-            require!(choice >= 2 && choice <= 12, CasinoError::InvalidChoice);
-        },
-        GameType::Slots => {
-            // Slots doesn't use choice (auto-spin), but we accept any value
-        },
-    }
+    validate_choice(game_type, choice)?;
     
     // Validate bet amount
     casino.validate_bet_amount(bet_amount)?;
+    enforce_player_limits(&ctx.accounts.player_limits, Some(bet_amount), clock.unix_timestamp)?;
     
     // Check player has sufficient balance (including rent + tx fees buffer)
     let player_balance = ctx.accounts.player.lamports();
@@ -79,19 +93,14 @@ pub fn handler(
         CasinoError::InsufficientPlayerFunds
     );
     
-    // Calculate potential payout
-    let potential_payout = bet_amount
-        .checked_mul(PAYOUT_MULTIPLIER_BP)
-        .ok_or(CasinoError::Overflow)?
-        .checked_div(BASIS_POINTS)
+    // Reserve the game's maximum payout: the vault (including this bet) must
+    // cover it on top of every other pending session and its own rent
+    let potential_payout = game_type.max_payout(choice, bet_amount)?;
+    let vault_balance = ctx.accounts.vault.lamports()
+        .checked_add(bet_amount)
         .ok_or(CasinoError::Overflow)?;
-    
-    // Check vault has enough liquidity for potential payout
-    let vault_balance = ctx.accounts.vault.lamports();
-    require!(
-        vault_balance >= potential_payout,
-        CasinoError::InsufficientVaultLiquidity
-    );
+    let rent_exempt_minimum = ctx.accounts.rent.minimum_balance(ctx.accounts.vault.data_len());
+    casino.reserve_liability(potential_payout, vault_balance, rent_exempt_minimum)?;
     
     // CRITICAL FIX: Increment game counter BEFORE using it (prevents PDA collision race condition)
     let game_id = casino.total_games;
@@ -115,39 +124,26 @@ pub fn handler(
     session.game_id = game_id;
     session.game_type = game_type;
     session.bet_amount = bet_amount;
+    session.mint = None;
+    session.from_balance = false;
     session.choice = choice;
     session.status = SessionStatus::Pending;
     session.created_at = clock.unix_timestamp;
+    session.created_slot = clock.slot;
+    session.max_payout = potential_payout;
     session.resolved_at = None;
     session.result = None;
     session.bump = *ctx.bumps.get("session").unwrap();
     
-    // ATOMIC STEP 3: Request randomness
-    // For localnet/testing: mock request
-    #[cfg(feature = "mock-vrf")]
-    {
-        session.randomness_request = RandomnessRequest {
-            is_mock: true,
-            request_id: format!("mock_{}", game_id),
-            switchboard_request: None,
-        };
-        
-        msg!("Mock VRF request created for localnet testing");
-    }
-    
-    // For devnet/mainnet: real Switchboard VRF
-    #[cfg(not(feature = "mock-vrf"))]
-    {
-        // TODO: Implement real Switchboard VRF request
-        // For now, use mock structure but mark as non-mock
-        session.randomness_request = RandomnessRequest {
-            is_mock: false,
-            request_id: format!("sb_{}", game_id),
-            switchboard_request: casino.switchboard_function,
-        };
-        
-        msg!("Switchboard VRF request would be created here");
-    }
+    // ATOMIC STEP 3: Request randomness from the casino's current source
+    session.randomness_request = request_randomness(
+        casino,
+        ctx.accounts.randomness_account.as_ref(),
+        ctx.accounts.seed_chain.as_ref(),
+        clock,
+        game_id,
+        client_seed,
+    )?;
     
     // Update casino stats
     casino.total_volume = casino.total_volume
@@ -164,3 +160,115 @@ pub fn handler(
     Ok(())
 }
 
+/// Validate the player's choice for the game type
+pub(crate) fn validate_choice(game_type: GameType, choice: u8) -> Result<()> {
+    match game_type {
+        GameType::CoinFlip => {
+            require!(choice == 0 || choice == 1, CasinoError::InvalidChoice);
+        },
+        GameType::Dice => {
+            require!((2..=12).contains(&choice), CasinoError::InvalidChoice);
+        },
+        GameType::Slots => {
+            // Slots doesn't use choice (auto-spin), but we accept any value
+        },
+    }
+    Ok(())
+}
+
+/// Enforce the player's `PlayerLimits`, if they created one, and record the
+/// bet. `wager` is `None` for bets not in lamports, which are only subject to
+/// self-exclusion.
+pub(crate) fn enforce_player_limits(
+    player_limits: &UncheckedAccount,
+    wager: Option<u64>,
+    now: i64,
+) -> Result<()> {
+    let info = player_limits.to_account_info();
+    let Some(mut limits) = load_if_initialized::<PlayerLimits>(&info)? else {
+        return Ok(());
+    };
+    match wager {
+        Some(amount) => limits.record_bet(amount, now)?,
+        None => {
+            limits.apply_pending(now);
+            limits.require_not_excluded(now)?;
+        },
+    }
+    limits.exit(&crate::ID)
+}
+
+/// Build the randomness request of a new session from the casino's current
+/// source. Shared by every bet instruction.
+pub(crate) fn request_randomness(
+    casino: &CasinoConfig,
+    randomness_account: Option<&UncheckedAccount>,
+    seed_chain: Option<&Account<SeedChain>>,
+    clock: &Clock,
+    game_id: u64,
+    client_seed: Option<String>,
+) -> Result<RandomnessRequest> {
+    let source = casino.randomness_source;
+    CasinoConfig::validate_randomness_source(source)?;
+    require!(
+        (source == RandomnessSource::CommitReveal || client_seed.is_none())
+            && (source == RandomnessSource::Switchboard || randomness_account.is_none()),
+        CasinoError::RandomnessSourceMismatch
+    );
+    
+    let mut request = RandomnessRequest {
+        source,
+        request_id: String::new(),
+        switchboard_request: None,
+        commit_slot: 0,
+        oracle: None,
+        oracle_signature_hash: None,
+        server_seed_hash: None,
+        client_seed: String::new(),
+    };
+    
+    match source {
+        RandomnessSource::Mock => {
+            request.request_id = format!("mock_{}", game_id);
+            msg!("Mock VRF request created for localnet testing");
+        },
+        RandomnessSource::OracleSigned => {
+            request.request_id = format!("oracle_{}", game_id);
+            request.oracle = Some(casino.oracle);
+            msg!("Awaiting oracle-signed randomness");
+        },
+        RandomnessSource::SlotHash => {
+            request.request_id = format!("slot_{}", game_id);
+            msg!("Awaiting slot hash of slot {}", clock.slot);
+        },
+        RandomnessSource::Switchboard => {
+            // Switchboard On-Demand: bind the freshly committed randomness account
+            let randomness_account = randomness_account.ok_or(CasinoError::RandomnessSourceMismatch)?;
+            let randomness = SwitchboardRandomness::load(&randomness_account.to_account_info())?;
+            randomness.require_fresh_commit(clock.slot)?;
+            
+            request.request_id = format!("sb_{}", game_id);
+            request.switchboard_request = Some(randomness_account.key());
+            request.commit_slot = randomness.seed_slot;
+            
+            msg!("Switchboard randomness bound: {}", randomness_account.key());
+        },
+        RandomnessSource::CommitReveal => {
+            // Commit-reveal: bind the bet to the current server-seed commitment
+            let client_seed = client_seed.ok_or(CasinoError::InvalidClientSeed)?;
+            require!(
+                !client_seed.is_empty() && client_seed.len() <= MAX_CLIENT_SEED_LEN,
+                CasinoError::InvalidClientSeed
+            );
+            let seed_chain = seed_chain.ok_or(CasinoError::RandomnessSourceMismatch)?;
+            
+            request.request_id = format!("cr_{}_{}", seed_chain.chain_id, game_id);
+            request.server_seed_hash = Some(seed_chain.commitment);
+            request.client_seed = client_seed;
+            
+            msg!("Bound to seed chain {} (nonce {})", seed_chain.chain_id, game_id);
+        },
+    }
+    
+    Ok(request)
+}
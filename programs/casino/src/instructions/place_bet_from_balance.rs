@@ -0,0 +1,181 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use crate::state::*;
+use crate::errors::CasinoError;
+use super::place_bet::{enforce_player_limits, request_randomness, validate_choice};
+
+#[derive(Accounts)]
+pub struct PlaceBetFromBalance<'info> {
+    #[account(
+        mut,
+        seeds = [CASINO_SEED],
+        bump,
+        constraint = casino.is_operational() @ CasinoError::CasinoPaused,
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + GameSession::INIT_SPACE,
+        seeds = [
+            SESSION_SEED,
+            player.key().as_ref(),
+            &casino.total_games.to_le_bytes()
+        ],
+        bump
+    )]
+    pub session: Account<'info, GameSession>,
+    
+    #[account(
+        mut,
+        seeds = [PLAYER_BALANCE_SEED, casino.key().as_ref(), player.key().as_ref()],
+        bump = player_balance.bump,
+    )]
+    pub player_balance: Account<'info, PlayerBalance>,
+    
+    /// Vault PDA already holding the balance; refunds a session key's rent
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, casino.key().as_ref()],
+        bump = casino.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    /// Wallet whose balance funds the bet
+    /// CHECK: Authorized by `signer` being this wallet or one of its session keys
+    pub player: UncheckedAccount<'info>,
+    
+    /// The player's wallet or a session key authorized by it. Fronts the
+    /// session rent; a session key is paid back from the balance.
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    
+    /// Session key of `signer` (only when the wallet itself does not sign)
+    #[account(
+        mut,
+        seeds = [SESSION_KEY_SEED, casino.key().as_ref(), player.key().as_ref(), signer.key().as_ref()],
+        bump = session_key.bump,
+    )]
+    pub session_key: Option<Account<'info, SessionKey>>,
+    
+    /// Player's `PlayerLimits` PDA, enforced once it exists
+    /// CHECK: Address verified via seeds; deserialized in the handler if initialized
+    #[account(
+        mut,
+        seeds = [PLAYER_LIMITS_SEED, casino.key().as_ref(), player.key().as_ref()],
+        bump,
+    )]
+    pub player_limits: UncheckedAccount<'info>,
+    
+    /// Switchboard On-Demand randomness account committed within the last few
+    /// slots (Switchboard source only)
+    /// CHECK: Owner, discriminator and freshness verified in the handler
+    pub randomness_account: Option<UncheckedAccount<'info>>,
+    
+    /// Server-seed chain (commit-reveal source only)
+    #[account(
+        seeds = [SEED_CHAIN_SEED, casino.key().as_ref()],
+        bump = seed_chain.bump,
+    )]
+    pub seed_chain: Option<Account<'info, SeedChain>>,
+    
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// `place_bet` funded from the player's `PlayerBalance` instead of a
+/// transfer. Settlement credits wins back to the balance. Signed by the
+/// wallet, or by a session key within its limits. The session rent goes back
+/// to the wallet when the session is closed, so a session key's rent is taken
+/// from the balance rather than from the key.
+pub fn handler(
+    ctx: Context<PlaceBetFromBalance>,
+    game_type: GameType,
+    choice: u8,
+    bet_amount: u64,
+    client_seed: Option<String>,
+) -> Result<()> {
+    let casino = &mut ctx.accounts.casino;
+    let player_balance = &mut ctx.accounts.player_balance;
+    let session = &mut ctx.accounts.session;
+    let clock = &ctx.accounts.clock;
+    let signed_by_session_key = ctx.accounts.signer.key() != ctx.accounts.player.key();
+    
+    validate_choice(game_type, choice)?;
+    casino.validate_bet_amount(bet_amount)?;
+    
+    if signed_by_session_key {
+        ctx.accounts.session_key
+            .as_mut()
+            .ok_or(CasinoError::Unauthorized)?
+            .authorize_bet(game_type, bet_amount, clock.unix_timestamp)?;
+    }
+    
+    enforce_player_limits(&ctx.accounts.player_limits, Some(bet_amount), clock.unix_timestamp)?;
+    
+    // The bet stays in the vault, it just stops being owed to the player
+    player_balance.debit(bet_amount)?;
+    casino.remove_player_balance(bet_amount)?;
+    
+    if signed_by_session_key {
+        let rent = session.to_account_info().lamports();
+        player_balance.debit(rent)?;
+        casino.remove_player_balance(rent)?;
+        
+        let casino_key = casino.key();
+        system_program::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.vault.to_account_info(),
+                    to: ctx.accounts.signer.to_account_info(),
+                },
+                &[&[VAULT_SEED, casino_key.as_ref(), &[casino.vault_bump]]],
+            ),
+            rent
+        )?;
+    }
+    
+    let potential_payout = game_type.max_payout(choice, bet_amount)?;
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(ctx.accounts.vault.data_len());
+    casino.reserve_liability(potential_payout, ctx.accounts.vault.lamports(), rent_exempt_minimum)?;
+    
+    let game_id = casino.total_games;
+    casino.total_games = casino.total_games
+        .checked_add(1)
+        .ok_or(CasinoError::Overflow)?;
+    
+    session.player = ctx.accounts.player.key();
+    session.game_id = game_id;
+    session.game_type = game_type;
+    session.bet_amount = bet_amount;
+    session.mint = None;
+    session.from_balance = true;
+    session.choice = choice;
+    session.status = SessionStatus::Pending;
+    session.created_at = clock.unix_timestamp;
+    session.created_slot = clock.slot;
+    session.max_payout = potential_payout;
+    session.resolved_at = None;
+    session.result = None;
+    session.bump = *ctx.bumps.get("session").unwrap();
+    
+    session.randomness_request = request_randomness(
+        casino,
+        ctx.accounts.randomness_account.as_ref(),
+        ctx.accounts.seed_chain.as_ref(),
+        clock,
+        game_id,
+        client_seed,
+    )?;
+    
+    casino.total_volume = casino.total_volume
+        .checked_add(bet_amount)
+        .ok_or(CasinoError::Overflow)?;
+    
+    msg!("Bet placed from balance: game {}, {} lamports", game_id, bet_amount);
+    msg!("Remaining balance: {} lamports", player_balance.balance);
+    
+    Ok(())
+}
@@ -0,0 +1,173 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::*;
+use crate::errors::CasinoError;
+use crate::token_extensions;
+use super::place_bet::{enforce_player_limits, request_randomness, validate_choice};
+
+#[derive(Accounts)]
+pub struct PlaceTokenBet<'info> {
+    #[account(
+        mut,
+        seeds = [CASINO_SEED],
+        bump,
+        constraint = casino.is_operational() @ CasinoError::CasinoPaused,
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        mut,
+        seeds = [TOKEN_VAULT_SEED, casino.key().as_ref(), mint.key().as_ref()],
+        bump = token_vault.bump,
+        has_one = mint,
+        has_one = vault_token_account,
+        constraint = token_vault.is_active @ CasinoError::TokenVaultInactive,
+    )]
+    pub token_vault: Account<'info, TokenVault>,
+    
+    #[account(
+        init,
+        payer = player,
+        space = 8 + GameSession::INIT_SPACE,
+        seeds = [
+            SESSION_SEED,
+            player.key().as_ref(),
+            &casino.total_games.to_le_bytes()
+        ],
+        bump
+    )]
+    pub session: Account<'info, GameSession>,
+    
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    /// Receives the bet
+    #[account(mut)]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = player,
+    )]
+    pub player_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    /// Player's `PlayerLimits` PDA; only its self-exclusion applies to token bets
+    /// CHECK: Address verified via seeds; deserialized in the handler if initialized
+    #[account(
+        mut,
+        seeds = [PLAYER_LIMITS_SEED, casino.key().as_ref(), player.key().as_ref()],
+        bump,
+    )]
+    pub player_limits: UncheckedAccount<'info>,
+    
+    /// Switchboard On-Demand randomness account committed within the last few
+    /// slots (Switchboard source only)
+    /// CHECK: Owner, discriminator and freshness verified in the handler
+    pub randomness_account: Option<UncheckedAccount<'info>>,
+    
+    /// Server-seed chain (commit-reveal source only)
+    #[account(
+        seeds = [SEED_CHAIN_SEED, casino.key().as_ref()],
+        bump = seed_chain.bump,
+    )]
+    pub seed_chain: Option<Account<'info, SeedChain>>,
+    
+    #[account(address = token_vault.token_program @ CasinoError::MintMismatch)]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// `place_bet` for an SPL token bet. Liability is reserved against the
+/// mint's token vault rather than the SOL vault. The recorded `bet_amount` is
+/// the amount received after transfer fees; limits apply to it.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, PlaceTokenBet<'info>>,
+    game_type: GameType,
+    choice: u8,
+    bet_amount: u64,
+    client_seed: Option<String>,
+) -> Result<()> {
+    let casino = &mut ctx.accounts.casino;
+    let token_vault = &mut ctx.accounts.token_vault;
+    let session = &mut ctx.accounts.session;
+    let clock = &ctx.accounts.clock;
+    
+    validate_choice(game_type, choice)?;
+    token_extensions::validate_mint(&ctx.accounts.mint.to_account_info(), token_vault.transfer_hook_program)?;
+    enforce_player_limits(&ctx.accounts.player_limits, None, clock.unix_timestamp)?;
+    
+    require!(
+        ctx.accounts.player_token_account.amount >= bet_amount,
+        CasinoError::InsufficientPlayerFunds
+    );
+    
+    let game_id = casino.total_games;
+    casino.total_games = casino.total_games
+        .checked_add(1)
+        .ok_or(CasinoError::Overflow)?;
+    
+    let vault_balance_before = ctx.accounts.vault_token_account.amount;
+    token_extensions::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.player_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.vault_token_account.to_account_info(),
+                authority: ctx.accounts.player.to_account_info(),
+            },
+        ).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        bet_amount,
+        ctx.accounts.mint.decimals,
+    )?;
+    
+    // The bet is what actually arrived: Token-2022 transfer fees are withheld
+    // from the transferred amount
+    ctx.accounts.vault_token_account.reload()?;
+    let vault_balance = ctx.accounts.vault_token_account.amount;
+    let bet_amount = vault_balance
+        .checked_sub(vault_balance_before)
+        .ok_or(CasinoError::Overflow)?;
+    token_vault.validate_bet_amount(bet_amount)?;
+    
+    // Reserve the maximum payout against the mint's bankroll (already holding the bet)
+    let potential_payout = game_type.max_payout(choice, bet_amount)?;
+    token_vault.reserve_liability(potential_payout, vault_balance)?;
+    
+    session.player = ctx.accounts.player.key();
+    session.game_id = game_id;
+    session.game_type = game_type;
+    session.bet_amount = bet_amount;
+    session.mint = Some(token_vault.mint);
+    session.from_balance = false;
+    session.choice = choice;
+    session.status = SessionStatus::Pending;
+    session.created_at = clock.unix_timestamp;
+    session.created_slot = clock.slot;
+    session.max_payout = potential_payout;
+    session.resolved_at = None;
+    session.result = None;
+    session.bump = *ctx.bumps.get("session").unwrap();
+    
+    session.randomness_request = request_randomness(
+        casino,
+        ctx.accounts.randomness_account.as_ref(),
+        ctx.accounts.seed_chain.as_ref(),
+        clock,
+        game_id,
+        client_seed,
+    )?;
+    
+    token_vault.total_volume = token_vault.total_volume
+        .checked_add(bet_amount)
+        .ok_or(CasinoError::Overflow)?;
+    
+    msg!("Token bet placed: game {}, {} of mint {}", game_id, bet_amount, token_vault.mint);
+    msg!("Session PDA: {}", ctx.accounts.session.key());
+    
+    Ok(())
+}
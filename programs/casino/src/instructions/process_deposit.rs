@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+pub struct ProcessDeposit<'info> {
+    #[account(
+        seeds = [CASINO_SEED],
+        bump,
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        mut,
+        seeds = [LIQUIDITY_POOL_SEED, casino.key().as_ref()],
+        bump = liquidity_pool.bump,
+        has_one = lp_mint,
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+    
+    #[account(mut)]
+    pub lp_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [DEPOSIT_REQUEST_SEED, casino.key().as_ref(), owner.key().as_ref()],
+        bump = deposit_request.bump,
+        has_one = owner,
+        has_one = owner_shares,
+        close = owner,
+    )]
+    pub deposit_request: Account<'info, DepositRequest>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, casino.key().as_ref()],
+        bump = casino.vault_bump
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    /// CHECK: Receives the request's rent (and a refund); checked by has_one
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub owner_shares: Account<'info, TokenAccount>,
+    
+    pub token_program: Program<'info, Token>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Permissionless crank for a queued deposit once its cooldown has elapsed.
+/// Moves the lamports into the vault and mints shares at the epoch's NAV
+/// snapshot, or refunds the deposit if that is below its `min_shares`.
+pub fn handler(ctx: Context<ProcessDeposit>) -> Result<()> {
+    let casino = &ctx.accounts.casino;
+    let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+    let deposit_request = &ctx.accounts.deposit_request;
+    let clock = Clock::get()?;
+    let amount = deposit_request.amount;
+    
+    require!(
+        deposit_request.cooldown_elapsed(clock.unix_timestamp),
+        CasinoError::DepositCooldownActive
+    );
+    
+    let nav = casino.net_asset_value(
+        ctx.accounts.vault.lamports(),
+        ctx.accounts.rent.minimum_balance(0),
+    )?;
+    liquidity_pool.snapshot_nav(clock.epoch, nav, ctx.accounts.lp_mint.supply, clock.unix_timestamp);
+    
+    let shares = liquidity_pool.shares_for_queued_deposit(deposit_request)?;
+    let accepted = shares > 0 && shares >= deposit_request.min_shares;
+    
+    // The request is owned by this program, so its lamports move directly
+    let destination = if accepted {
+        ctx.accounts.vault.to_account_info()
+    } else {
+        ctx.accounts.owner.to_account_info()
+    };
+    let request_info = deposit_request.to_account_info();
+    **request_info.try_borrow_mut_lamports()? = request_info.lamports()
+        .checked_sub(amount)
+        .ok_or(CasinoError::Overflow)?;
+    **destination.try_borrow_mut_lamports()? = destination.lamports()
+        .checked_add(amount)
+        .ok_or(CasinoError::Overflow)?;
+    
+    if accepted {
+        let casino_key = casino.key();
+        token::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.lp_mint.to_account_info(),
+                    to: ctx.accounts.owner_shares.to_account_info(),
+                    authority: liquidity_pool.to_account_info(),
+                },
+                &[&[LIQUIDITY_POOL_SEED, casino_key.as_ref(), &[liquidity_pool.bump]]],
+            ),
+            shares
+        )?;
+        
+        liquidity_pool.total_deposited = liquidity_pool.total_deposited
+            .checked_add(amount)
+            .ok_or(CasinoError::Overflow)?;
+        
+        // Later deposits this epoch are priced at the same NAV per share
+        liquidity_pool.snapshot_nav = liquidity_pool.snapshot_nav
+            .checked_add(amount)
+            .ok_or(CasinoError::Overflow)?;
+        liquidity_pool.snapshot_supply = liquidity_pool.snapshot_supply
+            .checked_add(shares)
+            .ok_or(CasinoError::Overflow)?;
+    }
+    
+    liquidity_pool.queued_deposits = liquidity_pool.queued_deposits
+        .checked_sub(amount)
+        .ok_or(CasinoError::Overflow)?;
+    
+    msg!(
+        "Deposit of {} lamports {} ({} shares at NAV {} / supply {})",
+        amount,
+        if accepted { "processed" } else { "refunded" },
+        shares,
+        liquidity_pool.snapshot_nav,
+        liquidity_pool.snapshot_supply
+    );
+    
+    emit!(LiquidityDeposited {
+        depositor: deposit_request.owner,
+        amount,
+        shares: if accepted { shares } else { 0 },
+        refunded: !accepted,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct LiquidityDeposited {
+    pub depositor: Pubkey,
+    pub amount: u64,
+    pub shares: u64,
+    /// Below `min_shares` at the snapshot price; the lamports went back
+    pub refunded: bool,
+}
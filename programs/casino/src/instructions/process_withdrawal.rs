@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+pub struct ProcessWithdrawal<'info> {
+    #[account(
+        seeds = [CASINO_SEED],
+        bump,
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        mut,
+        seeds = [LIQUIDITY_POOL_SEED, casino.key().as_ref()],
+        bump = liquidity_pool.bump,
+        has_one = lp_mint,
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+    
+    #[account(mut)]
+    pub lp_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [LP_ESCROW_SEED, casino.key().as_ref()],
+        bump = liquidity_pool.escrow_bump,
+    )]
+    pub lp_escrow: Account<'info, TokenAccount>,
+    
+    /// Head of the queue; the seeds only match `queue_head`, so requests are paid in order
+    #[account(
+        mut,
+        seeds = [
+            WITHDRAWAL_REQUEST_SEED,
+            casino.key().as_ref(),
+            liquidity_pool.queue_head.to_le_bytes().as_ref()
+        ],
+        bump = withdrawal_request.bump,
+        has_one = owner,
+    )]
+    pub withdrawal_request: Account<'info, WithdrawalRequest>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, casino.key().as_ref()],
+        bump = casino.vault_bump
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    /// CHECK: Receives the lamports and the request's rent; checked by has_one
+    #[account(mut)]
+    pub owner: UncheckedAccount<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+/// Permissionless crank for the head of the withdrawal queue. Pays the
+/// request at the epoch's NAV snapshot, partially if the vault cannot cover
+/// it without dipping into liabilities, and closes it once fully paid.
+pub fn handler(ctx: Context<ProcessWithdrawal>) -> Result<()> {
+    let casino = &ctx.accounts.casino;
+    let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+    let withdrawal_request = &mut ctx.accounts.withdrawal_request;
+    let clock = Clock::get()?;
+    
+    let mut shares_filled = 0;
+    let mut lamports = 0;
+    
+    // Cancelled requests are just removed from the queue
+    if withdrawal_request.shares_remaining > 0 {
+        require!(
+            withdrawal_request.cooldown_elapsed(clock.unix_timestamp),
+            CasinoError::WithdrawalCooldownActive
+        );
+        
+        let nav = casino.net_asset_value(
+            ctx.accounts.vault.lamports(),
+            ctx.accounts.rent.minimum_balance(0),
+        )?;
+        liquidity_pool.snapshot_nav(clock.epoch, nav, ctx.accounts.lp_mint.supply, clock.unix_timestamp);
+        
+        (shares_filled, lamports) = liquidity_pool.fill(withdrawal_request.shares_remaining, nav)?;
+        require!(shares_filled > 0, CasinoError::NoWithdrawableLiquidity);
+        
+        let casino_key = casino.key();
+        token::burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.lp_mint.to_account_info(),
+                    from: ctx.accounts.lp_escrow.to_account_info(),
+                    authority: liquidity_pool.to_account_info(),
+                },
+                &[&[LIQUIDITY_POOL_SEED, casino_key.as_ref(), &[liquidity_pool.bump]]],
+            ),
+            shares_filled
+        )?;
+        
+        if lamports > 0 {
+            system_program::transfer(
+                CpiContext::new_with_signer(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.vault.to_account_info(),
+                        to: ctx.accounts.owner.to_account_info(),
+                    },
+                    &[&[VAULT_SEED, casino_key.as_ref(), &[casino.vault_bump]]],
+                ),
+                lamports
+            )?;
+        }
+        
+        withdrawal_request.shares_remaining -= shares_filled;
+        withdrawal_request.lamports_paid = withdrawal_request.lamports_paid
+            .checked_add(lamports)
+            .ok_or(CasinoError::Overflow)?;
+        liquidity_pool.queued_shares = liquidity_pool.queued_shares
+            .checked_sub(shares_filled)
+            .ok_or(CasinoError::Overflow)?;
+        liquidity_pool.total_withdrawn = liquidity_pool.total_withdrawn
+            .checked_add(lamports)
+            .ok_or(CasinoError::Overflow)?;
+    }
+    
+    let completed = withdrawal_request.shares_remaining == 0;
+    if completed {
+        liquidity_pool.queue_head = liquidity_pool.queue_head
+            .checked_add(1)
+            .ok_or(CasinoError::Overflow)?;
+        withdrawal_request.close(ctx.accounts.owner.to_account_info())?;
+    }
+    
+    msg!(
+        "Withdrawal request {}: {} shares for {} lamports, {} shares left",
+        withdrawal_request.id,
+        shares_filled,
+        lamports,
+        withdrawal_request.shares_remaining
+    );
+    
+    emit!(WithdrawalProcessed {
+        id: withdrawal_request.id,
+        owner: withdrawal_request.owner,
+        shares: shares_filled,
+        lamports,
+        shares_remaining: withdrawal_request.shares_remaining,
+        snapshot_nav: liquidity_pool.snapshot_nav,
+        snapshot_supply: liquidity_pool.snapshot_supply,
+        completed,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct WithdrawalProcessed {
+    pub id: u64,
+    pub owner: Pubkey,
+    pub shares: u64,
+    pub lamports: u64,
+    pub shares_remaining: u64,
+    pub snapshot_nav: u64,
+    pub snapshot_supply: u64,
+    pub completed: bool,
+}
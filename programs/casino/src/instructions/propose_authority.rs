@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [CASINO_SEED],
+        bump,
+        has_one = authority @ CasinoError::Unauthorized
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+/// First step of an authority transfer: nominate the new authority, which
+/// must then sign `accept_authority`. Passing `None` cancels a pending proposal.
+/// While `timelock_delay` is non-zero, nominations go through `queue_change`.
+pub fn handler(ctx: Context<ProposeAuthority>, new_authority: Option<Pubkey>) -> Result<()> {
+    let casino = &mut ctx.accounts.casino;
+    
+    if new_authority.is_some() {
+        casino.require_no_timelock()?;
+    }
+    
+    casino.pending_authority = new_authority;
+    
+    match new_authority {
+        Some(proposed) => msg!("Authority transfer proposed to {}", proposed),
+        None => msg!("Authority transfer cancelled"),
+    }
+    
+    emit!(AuthorityProposed {
+        authority: casino.authority,
+        pending_authority: new_authority,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct AuthorityProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Option<Pubkey>,
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+pub struct QueueChange<'info> {
+    #[account(
+        mut,
+        seeds = [CASINO_SEED],
+        bump,
+        has_one = authority @ CasinoError::Unauthorized
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + PendingChange::INIT_SPACE,
+        seeds = [
+            PENDING_CHANGE_SEED,
+            casino.key().as_ref(),
+            &casino.next_change_id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub pending_change: Account<'info, PendingChange>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Queue a parameter change, executable after `casino.timelock_delay` seconds
+pub fn handler(ctx: Context<QueueChange>, change: ConfigChange) -> Result<()> {
+    let casino = &mut ctx.accounts.casino;
+    let pending_change = &mut ctx.accounts.pending_change;
+    let now = Clock::get()?.unix_timestamp;
+    
+    // Reject changes that could never execute; limits are checked against
+    // the vault balance at execution time
+    match &change {
+        ConfigChange::BetLimits { min_bet, max_bet } => {
+            require!(*min_bet > 0 && max_bet >= min_bet, CasinoError::InvalidBetAmount);
+        },
+        ConfigChange::Authority { .. }
+        | ConfigChange::Oracle { .. }
+        | ConfigChange::SwitchboardFunction { .. } => {},
+        ConfigChange::RandomnessSource { source } => {
+            CasinoConfig::validate_randomness_source(*source)?;
+        },
+        ConfigChange::TimelockDelay { delay } => {
+            require!(
+                (0..=MAX_TIMELOCK_DELAY_SECONDS).contains(delay),
+                CasinoError::InvalidTimelockDelay
+            );
+        },
+        ConfigChange::RevenueSplit { shares } => {
+            RevenueSplit::validate_shares(shares)?;
+        },
+        ConfigChange::TreasuryEpochCap { .. } => {},
+        ConfigChange::ReservePolicy { policy } => {
+            policy.validate()?;
+        },
+        ConfigChange::ReferralShare { share_bp } => {
+            CasinoConfig::validate_referral_share(*share_bp)?;
+        },
+    }
+    
+    let id = casino.next_change_id;
+    casino.next_change_id = casino.next_change_id
+        .checked_add(1)
+        .ok_or(CasinoError::Overflow)?;
+    
+    pending_change.id = id;
+    pending_change.change = change.clone();
+    pending_change.proposer = ctx.accounts.authority.key();
+    pending_change.queued_at = now;
+    pending_change.eta = now
+        .checked_add(casino.timelock_delay)
+        .ok_or(CasinoError::Overflow)?;
+    pending_change.bump = *ctx.bumps.get("pending_change").unwrap();
+    
+    msg!("Change {} queued, executable at {}", id, pending_change.eta);
+    
+    emit!(ChangeQueued {
+        id,
+        change,
+        eta: pending_change.eta,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct ChangeQueued {
+    pub id: u64,
+    pub change: ConfigChange,
+    pub eta: i64,
+}
@@ -1,7 +1,11 @@
 use anchor_lang::prelude::*;
 use anchor_lang::system_program::{self, Transfer};
+use anchor_lang::solana_program::sysvar;
 use crate::state::*;
 use crate::errors::CasinoError;
+use crate::randomness::slot_hash;
+use crate::randomness::switchboard::SwitchboardRandomness;
+use crate::utils::load_if_initialized;
 
 #[derive(Accounts)]
 pub struct RefundExpired<'info> {
@@ -20,6 +24,7 @@ pub struct RefundExpired<'info> {
             &session.game_id.to_le_bytes()
         ],
         bump = session.bump,
+        constraint = session.mint.is_none() @ CasinoError::MintMismatch,
         constraint = session.status == SessionStatus::Pending @ CasinoError::AlreadyResolved,
         close = player, // Close session and refund rent to player
     )]
@@ -42,6 +47,36 @@ pub struct RefundExpired<'info> {
     )]
     pub player: SystemAccount<'info>,
     
+    /// Balance the session was funded from (balance sessions only); the bet is credited back to it
+    #[account(
+        mut,
+        seeds = [PLAYER_BALANCE_SEED, casino.key().as_ref(), session.player.as_ref()],
+        bump = player_balance.bump,
+    )]
+    pub player_balance: Option<Account<'info, PlayerBalance>>,
+    
+    /// Player's limits; the refunded bet no longer counts as lost once it exists
+    /// CHECK: Address verified via seeds; deserialized in the handler if initialized
+    #[account(
+        mut,
+        seeds = [PLAYER_LIMITS_SEED, casino.key().as_ref(), session.player.as_ref()],
+        bump,
+    )]
+    pub player_limits: UncheckedAccount<'info>,
+    
+    /// Switchboard randomness account bound to the session (Switchboard sessions only)
+    /// CHECK: Loaded in the handler; it may have been closed or re-committed
+    #[account(
+        constraint = session.randomness_request.switchboard_request == Some(randomness_account.key())
+            @ CasinoError::InvalidRandomnessAccount,
+    )]
+    pub randomness_account: Option<UncheckedAccount<'info>>,
+    
+    /// SlotHashes sysvar, read to tell whether a public outcome can still be settled
+    /// CHECK: Address verified against the sysvar ID
+    #[account(address = sysvar::slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+    
     pub system_program: Program<'info, System>,
     pub clock: Sysvar<'info, Clock>,
 }
@@ -49,13 +84,18 @@ pub struct RefundExpired<'info> {
 pub fn handler(ctx: Context<RefundExpired>) -> Result<()> {
     let session = &mut ctx.accounts.session;
     let clock = &ctx.accounts.clock;
-    let casino = &ctx.accounts.casino;
+    let casino = &mut ctx.accounts.casino;
     
     // Session MUST be expired to refund
     require!(
         session.is_expired(clock.unix_timestamp),
         CasinoError::SessionNotExpiredYet
     );
+    let refunded = refund_due(
+        session,
+        ctx.accounts.randomness_account.as_ref(),
+        &ctx.accounts.slot_hashes.to_account_info(),
+    )?;
     
     msg!("Session expired. Refunding bet to player.");
     msg!("Session created at: {}", session.created_at);
@@ -65,27 +105,47 @@ pub fn handler(ctx: Context<RefundExpired>) -> Result<()> {
     // Refund the original bet from vault to player
     let bet_amount = session.bet_amount;
     
-    // Vault must sign for the transfer using PDA seeds
-    let casino_key = casino.key();
-    let vault_seeds = &[
-        VAULT_SEED,
-        casino_key.as_ref(),
-        &[casino.vault_bump],
-    ];
-    let signer_seeds = &[&vault_seeds[..]];
-    
-    let transfer_cpi = Transfer {
-        from: ctx.accounts.vault.to_account_info(),
-        to: ctx.accounts.player.to_account_info(),
-    };
-    let cpi_ctx = CpiContext::new_with_signer(
-        ctx.accounts.system_program.to_account_info(),
-        transfer_cpi,
-        signer_seeds,
-    );
-    system_program::transfer(cpi_ctx, bet_amount)?;
+    if !refunded {
+        msg!("Switchboard randomness withheld - bet forfeited");
+    } else if session.from_balance {
+        ctx.accounts.player_balance
+            .as_mut()
+            .ok_or(CasinoError::InsufficientPlayerBalance)?
+            .credit(bet_amount)?;
+        casino.add_player_balance(bet_amount)?;
+    } else {
+        // Vault must sign for the transfer using PDA seeds
+        let casino_key = casino.key();
+        let vault_seeds = &[
+            VAULT_SEED,
+            casino_key.as_ref(),
+            &[casino.vault_bump],
+        ];
+        let signer_seeds = &[&vault_seeds[..]];
+        
+        let transfer_cpi = Transfer {
+            from: ctx.accounts.vault.to_account_info(),
+            to: ctx.accounts.player.to_account_info(),
+        };
+        let cpi_ctx = CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            transfer_cpi,
+            signer_seeds,
+        );
+        system_program::transfer(cpi_ctx, bet_amount)?;
+    }
     
-    msg!("Bet refunded: {} lamports", bet_amount);
+    casino.release_liability(session.max_payout)?;
+    
+    // `place_bet` counted the bet as fully lost
+    let player_limits_info = ctx.accounts.player_limits.to_account_info();
+    if refunded {
+        if let Some(mut player_limits) = load_if_initialized::<PlayerLimits>(&player_limits_info)? {
+            player_limits.record_payout(bet_amount, clock.unix_timestamp)?;
+            player_limits.exit(&crate::ID)?;
+        }
+        msg!("Bet refunded: {} lamports", bet_amount);
+    }
     
     // Mark session as expired (for record keeping before close)
     session.status = SessionStatus::Expired;
@@ -99,3 +159,48 @@ pub fn handler(ctx: Context<RefundExpired>) -> Result<()> {
     Ok(())
 }
 
+/// Whether an expired session gets its bet back. Fails if the session has to
+/// be settled instead (`SessionNotRefundable`).
+///
+/// A slot-hash session's outcome is public from the slot after the bet, and an
+/// oracle session's once its signature is locked in. Those are only refunded
+/// once the slot's hash has left the SlotHashes window, when they can no longer
+/// be settled; a live settler settles every one of them before that.
+///
+/// A Switchboard randomness account is controlled by the player, who can read
+/// the reveal before anyone else, so its bet is never handed back: a session
+/// whose revealed commitment is intact must be settled, and one whose account
+/// was re-committed, closed or never revealed is forfeited to the house.
+pub(crate) fn refund_due(
+    session: &Account<GameSession>,
+    randomness_account: Option<&UncheckedAccount>,
+    slot_hashes: &AccountInfo,
+) -> Result<bool> {
+    let request = &session.randomness_request;
+    if request.source == RandomnessSource::Switchboard {
+        let randomness_account = randomness_account.ok_or(CasinoError::InvalidRandomnessAccount)?;
+        let settleable = SwitchboardRandomness::load(&randomness_account.to_account_info())
+            .and_then(|randomness| {
+                randomness.revealed_value(request.commit_slot, &session.key())
+            })
+            .is_ok();
+        require!(!settleable, CasinoError::SessionNotRefundable);
+        return Ok(false);
+    }
+    
+    let public_slot = if request.source == RandomnessSource::SlotHash {
+        Some(session.created_slot)
+    } else if request.oracle_signature_hash.is_some() {
+        Some(request.commit_slot)
+    } else {
+        None
+    };
+    if let Some(slot) = public_slot {
+        require!(
+            slot_hash::slot_hash(slot_hashes, slot) == Err(CasinoError::SlotHashUnavailable.into()),
+            CasinoError::SessionNotRefundable
+        );
+    }
+    
+    Ok(true)
+}
@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::*;
+use crate::errors::CasinoError;
+use crate::token_extensions;
+use super::refund_expired::refund_due;
+
+#[derive(Accounts)]
+pub struct RefundExpiredToken<'info> {
+    #[account(
+        seeds = [CASINO_SEED],
+        bump,
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        mut,
+        seeds = [
+            SESSION_SEED,
+            session.player.as_ref(),
+            &session.game_id.to_le_bytes()
+        ],
+        bump = session.bump,
+        constraint = session.status == SessionStatus::Pending @ CasinoError::AlreadyResolved,
+        constraint = session.mint == Some(token_vault.mint) @ CasinoError::MintMismatch,
+        close = player,
+    )]
+    pub session: Account<'info, GameSession>,
+    
+    #[account(
+        mut,
+        seeds = [TOKEN_VAULT_SEED, casino.key().as_ref(), mint.key().as_ref()],
+        bump = token_vault.bump,
+        has_one = mint,
+        has_one = vault_token_account,
+    )]
+    pub token_vault: Account<'info, TokenVault>,
+    
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(mut)]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Receives the refunded bet
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = player,
+    )]
+    pub player_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Receives the session rent
+    /// CHECK: Player pubkey verified against session
+    #[account(
+        mut,
+        constraint = player.key() == session.player @ CasinoError::Unauthorized,
+    )]
+    pub player: SystemAccount<'info>,
+    
+    /// Switchboard randomness account bound to the session (Switchboard sessions only)
+    /// CHECK: Loaded in the handler; it may have been closed or re-committed
+    #[account(
+        constraint = session.randomness_request.switchboard_request == Some(randomness_account.key())
+            @ CasinoError::InvalidRandomnessAccount,
+    )]
+    pub randomness_account: Option<UncheckedAccount<'info>>,
+    
+    /// SlotHashes sysvar, read to tell whether a public outcome can still be settled
+    /// CHECK: Address verified against the sysvar ID
+    #[account(address = sysvar::slot_hashes::ID)]
+    pub slot_hashes: UncheckedAccount<'info>,
+    
+    #[account(address = token_vault.token_program @ CasinoError::MintMismatch)]
+    pub token_program: Interface<'info, TokenInterface>,
+    pub clock: Sysvar<'info, Clock>,
+}
+
+/// `refund_expired` for token sessions. Token bets are not recorded in
+/// `PlayerLimits` (which counts lamports), so there is no loss to credit back.
+pub fn handler<'info>(ctx: Context<'_, '_, '_, 'info, RefundExpiredToken<'info>>) -> Result<()> {
+    let session = &mut ctx.accounts.session;
+    let token_vault = &mut ctx.accounts.token_vault;
+    let clock = &ctx.accounts.clock;
+    
+    require!(
+        session.is_expired(clock.unix_timestamp),
+        CasinoError::SessionNotExpiredYet
+    );
+    let refunded = refund_due(
+        session,
+        ctx.accounts.randomness_account.as_ref(),
+        &ctx.accounts.slot_hashes.to_account_info(),
+    )?;
+    let mint_key = token_vault.mint;
+    
+    if !refunded {
+        token_vault.release_liability(session.max_payout)?;
+        session.status = SessionStatus::Expired;
+        session.resolved_at = Some(clock.unix_timestamp);
+        msg!("Switchboard randomness withheld - token bet forfeited");
+        return Ok(());
+    }
+    
+    token_extensions::validate_mint(&ctx.accounts.mint.to_account_info(), token_vault.transfer_hook_program)?;
+    
+    let casino_key = ctx.accounts.casino.key();
+    
+    token_extensions::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.player_token_account.to_account_info(),
+                authority: token_vault.to_account_info(),
+            },
+            &[&[TOKEN_VAULT_SEED, casino_key.as_ref(), mint_key.as_ref(), &[token_vault.bump]]],
+        ).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        session.bet_amount,
+        ctx.accounts.mint.decimals,
+    )?;
+    
+    token_vault.release_liability(session.max_payout)?;
+    
+    session.status = SessionStatus::Expired;
+    session.resolved_at = Some(clock.unix_timestamp);
+    
+    msg!("Token bet refunded: {} of mint {}", session.bet_amount, mint_key);
+    
+    Ok(())
+}
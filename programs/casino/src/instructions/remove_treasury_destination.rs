@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+pub struct RemoveTreasuryDestination<'info> {
+    #[account(
+        seeds = [CASINO_SEED],
+        bump,
+        has_one = authority @ CasinoError::Unauthorized
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        mut,
+        seeds = [
+            TREASURY_DESTINATION_SEED,
+            casino.key().as_ref(),
+            treasury_destination.destination.as_ref()
+        ],
+        bump = treasury_destination.bump,
+        close = authority,
+    )]
+    pub treasury_destination: Account<'info, TreasuryDestination>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RemoveTreasuryDestination>) -> Result<()> {
+    let destination = ctx.accounts.treasury_destination.destination;
+    
+    msg!("Treasury destination removed: {}", destination);
+    
+    emit!(TreasuryDestinationRemoved { destination });
+    
+    Ok(())
+}
+
+#[event]
+pub struct TreasuryDestinationRemoved {
+    pub destination: Pubkey,
+}
@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Token, TokenAccount, Transfer};
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+pub struct RequestWithdrawal<'info> {
+    #[account(
+        seeds = [CASINO_SEED],
+        bump,
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        mut,
+        seeds = [LIQUIDITY_POOL_SEED, casino.key().as_ref()],
+        bump = liquidity_pool.bump,
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+    
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + WithdrawalRequest::INIT_SPACE,
+        seeds = [
+            WITHDRAWAL_REQUEST_SEED,
+            casino.key().as_ref(),
+            liquidity_pool.next_request_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub withdrawal_request: Account<'info, WithdrawalRequest>,
+    
+    #[account(
+        mut,
+        seeds = [LP_ESCROW_SEED, casino.key().as_ref()],
+        bump = liquidity_pool.escrow_bump,
+    )]
+    pub lp_escrow: Account<'info, TokenAccount>,
+    
+    #[account(
+        mut,
+        token::mint = liquidity_pool.lp_mint,
+        token::authority = owner,
+    )]
+    pub owner_shares: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Lock `shares` in the pool escrow and join the back of the withdrawal queue
+pub fn handler(ctx: Context<RequestWithdrawal>, shares: u64) -> Result<()> {
+    let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+    let withdrawal_request = &mut ctx.accounts.withdrawal_request;
+    let clock = Clock::get()?;
+    
+    require!(shares > 0, CasinoError::InvalidLiquidityAmount);
+    
+    token::transfer(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.owner_shares.to_account_info(),
+                to: ctx.accounts.lp_escrow.to_account_info(),
+                authority: ctx.accounts.owner.to_account_info(),
+            },
+        ),
+        shares
+    )?;
+    
+    withdrawal_request.id = liquidity_pool.next_request_id;
+    withdrawal_request.owner = ctx.accounts.owner.key();
+    withdrawal_request.shares_remaining = shares;
+    withdrawal_request.shares_requested = shares;
+    withdrawal_request.lamports_paid = 0;
+    withdrawal_request.requested_at = clock.unix_timestamp;
+    withdrawal_request.bump = *ctx.bumps.get("withdrawal_request").unwrap();
+    
+    liquidity_pool.next_request_id = liquidity_pool.next_request_id
+        .checked_add(1)
+        .ok_or(CasinoError::Overflow)?;
+    liquidity_pool.queued_shares = liquidity_pool.queued_shares
+        .checked_add(shares)
+        .ok_or(CasinoError::Overflow)?;
+    
+    msg!("Withdrawal request {} queued: {} shares", withdrawal_request.id, shares);
+    
+    emit!(WithdrawalRequested {
+        id: withdrawal_request.id,
+        owner: withdrawal_request.owner,
+        shares,
+        processable_at: clock.unix_timestamp.saturating_add(LP_WITHDRAWAL_COOLDOWN_SECONDS),
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct WithdrawalRequested {
+    pub id: u64,
+    pub owner: Pubkey,
+    pub shares: u64,
+    pub processable_at: i64,
+}
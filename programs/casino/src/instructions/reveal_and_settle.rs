@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+use crate::state::RandomnessSource;
+use crate::errors::CasinoError;
+use super::fulfill_randomness::{settle, verified_randomness, FulfillRandomness};
+
+/// Settle a commit-reveal session by revealing the server seed it was bound to.
+/// Permissionless: only the committed seed is accepted.
+pub fn handler(ctx: Context<FulfillRandomness>, server_seed: String) -> Result<()> {
+    require!(
+        ctx.accounts.session.randomness_request.source == RandomnessSource::CommitReveal,
+        CasinoError::InvalidRandomnessCallback
+    );
+    
+    let random_value = verified_randomness(ctx.accounts, [0u8; 32], Some(server_seed))?;
+    
+    settle(ctx.accounts, random_value)
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+pub struct RevokeRole<'info> {
+    #[account(
+        seeds = [CASINO_SEED],
+        bump,
+        has_one = authority @ CasinoError::Unauthorized
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        mut,
+        seeds = [
+            ROLE_SEED,
+            casino.key().as_ref(),
+            &role_assignment.role.seed(),
+            role_assignment.member.as_ref()
+        ],
+        bump = role_assignment.bump,
+        close = authority,
+    )]
+    pub role_assignment: Account<'info, RoleAssignment>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RevokeRole>) -> Result<()> {
+    let role_assignment = &ctx.accounts.role_assignment;
+    
+    msg!("Role revoked from {}", role_assignment.member);
+    
+    emit!(RoleRevoked {
+        role: role_assignment.role,
+        member: role_assignment.member,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct RoleRevoked {
+    pub role: Role,
+    pub member: Pubkey,
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+pub struct RevokeSessionKey<'info> {
+    #[account(
+        seeds = [CASINO_SEED],
+        bump,
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        mut,
+        seeds = [
+            SESSION_KEY_SEED,
+            casino.key().as_ref(),
+            player.key().as_ref(),
+            session_key.signer.as_ref()
+        ],
+        bump = session_key.bump,
+        has_one = player @ CasinoError::Unauthorized,
+        close = player,
+    )]
+    pub session_key: Account<'info, SessionKey>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+}
+
+pub fn handler(ctx: Context<RevokeSessionKey>) -> Result<()> {
+    let session_key = &ctx.accounts.session_key;
+    
+    msg!("Session key {} revoked", session_key.signer);
+    
+    emit!(SessionKeyRevoked {
+        player: session_key.player,
+        signer: session_key.signer,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct SessionKeyRevoked {
+    pub player: Pubkey,
+    pub signer: Pubkey,
+}
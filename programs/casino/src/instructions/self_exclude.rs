@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+pub struct SelfExclude<'info> {
+    #[account(
+        seeds = [CASINO_SEED],
+        bump,
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerLimits::INIT_SPACE,
+        seeds = [PLAYER_LIMITS_SEED, casino.key().as_ref(), player.key().as_ref()],
+        bump
+    )]
+    pub player_limits: Account<'info, PlayerLimits>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Block all bets for `duration` seconds. An existing exclusion is only ever
+/// extended, never shortened.
+pub fn handler(ctx: Context<SelfExclude>, duration: i64) -> Result<()> {
+    require!(duration > 0, CasinoError::InvalidPlayerLimits);
+    
+    let now = Clock::get()?.unix_timestamp;
+    let until = now
+        .checked_add(duration)
+        .ok_or(CasinoError::Overflow)?;
+    
+    let player_limits = &mut ctx.accounts.player_limits;
+    player_limits.player = ctx.accounts.player.key();
+    player_limits.bump = *ctx.bumps.get("player_limits").unwrap();
+    player_limits.self_exclude(until);
+    
+    msg!("Self-excluded until {}", player_limits.excluded_until);
+    
+    emit!(SelfExcluded {
+        player: player_limits.player,
+        excluded_until: player_limits.excluded_until,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct SelfExcluded {
+    pub player: Pubkey,
+    pub excluded_until: i64,
+}
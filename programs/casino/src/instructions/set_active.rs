@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct SetActive<'info> {
+    #[account(
+        mut,
+        seeds = [CASINO_SEED],
+        bump,
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    /// Pauser role held by the signer
+    #[account(
+        seeds = [ROLE_SEED, casino.key().as_ref(), &Role::Pauser.seed(), pauser.key().as_ref()],
+        bump = pauser_role.bump,
+    )]
+    pub pauser_role: Account<'info, RoleAssignment>,
+    
+    pub pauser: Signer<'info>,
+}
+
+/// Pause or unpause betting
+pub fn handler(ctx: Context<SetActive>, is_active: bool) -> Result<()> {
+    let casino = &mut ctx.accounts.casino;
+    
+    casino.is_active = is_active;
+    
+    msg!("Casino {}", if is_active { "unpaused" } else { "paused" });
+    
+    emit!(CasinoActiveChanged {
+        is_active,
+        pauser: ctx.accounts.pauser.key(),
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct CasinoActiveChanged {
+    pub is_active: bool,
+    pub pauser: Pubkey,
+}
@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct SetPlayerLimits<'info> {
+    #[account(
+        seeds = [CASINO_SEED],
+        bump,
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerLimits::INIT_SPACE,
+        seeds = [PLAYER_LIMITS_SEED, casino.key().as_ref(), player.key().as_ref()],
+        bump
+    )]
+    pub player_limits: Account<'info, PlayerLimits>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Replace the player's limits. Stricter values apply at once; looser ones
+/// (including removing a limit) only after `LIMIT_COOLING_OFF_SECONDS`.
+pub fn handler(ctx: Context<SetPlayerLimits>, limits: LimitSet) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let player_limits = &mut ctx.accounts.player_limits;
+    
+    player_limits.player = ctx.accounts.player.key();
+    player_limits.bump = *ctx.bumps.get("player_limits").unwrap();
+    player_limits.set_limits(limits, now, LIMIT_COOLING_OFF_SECONDS)?;
+    
+    msg!("Player limits updated");
+    if player_limits.pending_limits.is_some() {
+        msg!("Looser limits take effect at {}", player_limits.pending_effective_at);
+    }
+    
+    emit!(PlayerLimitsSet {
+        player: player_limits.player,
+        limits: player_limits.limits,
+        pending_limits: player_limits.pending_limits,
+        pending_effective_at: player_limits.pending_effective_at,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct PlayerLimitsSet {
+    pub player: Pubkey,
+    pub limits: LimitSet,
+    pub pending_limits: Option<LimitSet>,
+    pub pending_effective_at: i64,
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+pub struct SetRandomnessSource<'info> {
+    #[account(
+        mut,
+        seeds = [CASINO_SEED],
+        bump,
+        has_one = authority @ CasinoError::Unauthorized
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    pub authority: Signer<'info>,
+}
+
+/// Switch the randomness provider for new bets. Pending sessions keep the
+/// source they were placed with. Only while `timelock_delay` is zero;
+/// afterwards the switch has to be queued with `queue_change`.
+pub fn handler(ctx: Context<SetRandomnessSource>, source: RandomnessSource) -> Result<()> {
+    ctx.accounts.casino.require_no_timelock()?;
+    CasinoConfig::validate_randomness_source(source)?;
+    
+    let casino = &mut ctx.accounts.casino;
+    let old_source = casino.randomness_source;
+    casino.randomness_source = source;
+    
+    msg!("Randomness source updated");
+    
+    emit!(RandomnessSourceChanged {
+        old_source,
+        new_source: source,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct RandomnessSourceChanged {
+    pub old_source: RandomnessSource,
+    pub new_source: RandomnessSource,
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+pub struct SetRevenueSplit<'info> {
+    #[account(
+        seeds = [CASINO_SEED],
+        bump,
+        has_one = authority @ CasinoError::Unauthorized
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + RevenueSplit::INIT_SPACE,
+        seeds = [REVENUE_SPLIT_SEED, casino.key().as_ref()],
+        bump
+    )]
+    pub revenue_split: Account<'info, RevenueSplit>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the split table, or replace it while the timelock is off (otherwise
+/// replacements go through `queue_change`). Beneficiaries that stay in the
+/// table keep their cumulative `total_received`.
+pub fn handler(ctx: Context<SetRevenueSplit>, shares: Vec<BeneficiaryShare>) -> Result<()> {
+    RevenueSplit::validate_shares(&shares)?;
+    
+    let revenue_split = &mut ctx.accounts.revenue_split;
+    
+    if !revenue_split.beneficiaries.is_empty() {
+        ctx.accounts.casino.require_no_timelock()?;
+    }
+    
+    revenue_split.set_shares(&shares);
+    revenue_split.bump = *ctx.bumps.get("revenue_split").unwrap();
+    
+    msg!("Revenue split set: {} beneficiaries", shares.len());
+    
+    emit!(RevenueSplitSet { shares });
+    
+    Ok(())
+}
+
+#[event]
+pub struct RevenueSplitSet {
+    pub shares: Vec<BeneficiaryShare>,
+}
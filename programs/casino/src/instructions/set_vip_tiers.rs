@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+pub struct SetVipTiers<'info> {
+    #[account(
+        seeds = [CASINO_SEED],
+        bump,
+        has_one = authority @ CasinoError::Unauthorized
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + VipConfig::INIT_SPACE,
+        seeds = [VIP_CONFIG_SEED, casino.key().as_ref()],
+        bump
+    )]
+    pub vip_config: Account<'info, VipConfig>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Replace the tier table. Players keep tiers they already reached.
+pub fn handler(ctx: Context<SetVipTiers>, tiers: Vec<VipTier>) -> Result<()> {
+    VipConfig::validate_tiers(&tiers)?;
+    
+    let vip_config = &mut ctx.accounts.vip_config;
+    vip_config.tiers = tiers.clone();
+    vip_config.bump = *ctx.bumps.get("vip_config").unwrap();
+    
+    msg!("VIP tiers set: {} tiers", tiers.len());
+    
+    emit!(VipTiersSet { tiers });
+    
+    Ok(())
+}
+
+#[event]
+pub struct VipTiersSet {
+    pub tiers: Vec<VipTier>,
+}
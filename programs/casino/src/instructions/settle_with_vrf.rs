@@ -0,0 +1,18 @@
+use anchor_lang::prelude::*;
+use crate::state::RandomnessSource;
+use crate::errors::CasinoError;
+use super::fulfill_randomness::{settle, verified_randomness, FulfillRandomness};
+
+/// Settle a Switchboard session once the oracle has revealed the randomness
+/// bound to it in `place_bet`. Permissionless: the outcome is fixed by the
+/// revealed value, and wins are only paid out immediately when the player calls.
+pub fn handler(ctx: Context<FulfillRandomness>) -> Result<()> {
+    require!(
+        ctx.accounts.session.randomness_request.source == RandomnessSource::Switchboard,
+        CasinoError::InvalidRandomnessCallback
+    );
+    
+    let random_value = verified_randomness(ctx.accounts, [0u8; 32], None)?;
+    
+    settle(ctx.accounts, random_value)
+}
@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
 use crate::state::*;
 use crate::errors::CasinoError;
 
@@ -8,10 +9,16 @@ pub struct SkimExcessToTreasury<'info> {
         mut,
         seeds = [CASINO_SEED],
         bump,
-        has_one = authority @ CasinoError::Unauthorized
     )]
     pub casino: Account<'info, CasinoConfig>,
     
+    /// Treasurer role held by the signer
+    #[account(
+        seeds = [ROLE_SEED, casino.key().as_ref(), &Role::Treasurer.seed(), treasurer.key().as_ref()],
+        bump = treasurer_role.bump,
+    )]
+    pub treasurer_role: Account<'info, RoleAssignment>,
+    
     #[account(
         mut,
         seeds = [VAULT_SEED, casino.key().as_ref()],
@@ -26,11 +33,14 @@ pub struct SkimExcessToTreasury<'info> {
     )]
     pub treasury: SystemAccount<'info>,
     
-    pub authority: Signer<'info>,
+    pub treasurer: Signer<'info>,
     
     pub system_program: Program<'info, System>,
 }
 
+/// Move `amount` from the vault to the Treasury. The vault must keep the
+/// `ReservePolicy` balance (see `crank_skim`) plus `min_vault_reserve`, an
+/// optional extra margin chosen by the treasurer.
 pub fn handler(
     ctx: Context<SkimExcessToTreasury>,
     amount: u64,
@@ -47,9 +57,15 @@ pub fn handler(
     msg!("Min vault reserve: {} lamports", min_vault_reserve);
     
     require!(amount > 0, CasinoError::InvalidSkimAmount);
+    casino.require_no_liquidity_pool()?;
+    
+    // Never skim funds owed to players or the reserve the policy requires
+    let policy_balance = casino.required_vault_balance(Rent::get()?.minimum_balance(0))?;
+    msg!("Required vault balance: {} lamports", policy_balance);
     
     let required_balance = amount
-        .checked_add(min_vault_reserve)
+        .checked_add(policy_balance)
+        .and_then(|balance| balance.checked_add(min_vault_reserve))
         .ok_or(CasinoError::Overflow)?;
     
     require!(
@@ -57,8 +73,20 @@ pub fn handler(
         CasinoError::InsufficientVaultLiquidity
     );
     
-    **vault.to_account_info().try_borrow_mut_lamports()? -= amount;
-    **treasury.to_account_info().try_borrow_mut_lamports()? += amount;
+    // The vault is owned by the System Program, so it can only pay through a
+    // signed transfer
+    let casino_key = casino.key();
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: vault.to_account_info(),
+                to: treasury.to_account_info(),
+            },
+            &[&[VAULT_SEED, casino_key.as_ref(), &[casino.vault_bump]]],
+        ),
+        amount
+    )?;
     
     casino.total_treasury_skimmed = casino
         .total_treasury_skimmed
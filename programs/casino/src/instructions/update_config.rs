@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    #[account(
+        mut,
+        seeds = [CASINO_SEED],
+        bump,
+        has_one = authority @ CasinoError::Unauthorized
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    /// Vault PDA, read to validate max_bet against its balance
+    #[account(
+        seeds = [VAULT_SEED, casino.key().as_ref()],
+        bump = casino.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    pub authority: Signer<'info>,
+}
+
+/// Fields to change; `None` leaves the current value untouched. Bet limits,
+/// the oracle and the Switchboard function, and any raise of the Treasury cap
+/// or referral share or loosening of the reserve policy, can only be changed
+/// here while `timelock_delay` is zero (see `queue_change`). Tightening is
+/// always instant.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct ConfigUpdate {
+    pub min_bet: Option<u64>,
+    pub max_bet: Option<u64>,
+    pub is_active: Option<bool>,
+    /// `Some(None)` clears the Switchboard function
+    pub switchboard_function: Option<Option<Pubkey>>,
+    pub oracle: Option<Pubkey>,
+    pub treasury_epoch_cap: Option<u64>,
+    pub reserve_policy: Option<ReservePolicy>,
+    pub referral_share_bp: Option<u64>,
+    pub auto_close_sessions: Option<bool>,
+}
+
+pub fn handler(ctx: Context<UpdateConfig>, update: ConfigUpdate) -> Result<()> {
+    let casino = &mut ctx.accounts.casino;
+    let vault_balance = ctx.accounts.vault.lamports();
+    
+    let event = ConfigUpdated {
+        old_min_bet: casino.min_bet,
+        new_min_bet: update.min_bet.unwrap_or(casino.min_bet),
+        old_max_bet: casino.max_bet,
+        new_max_bet: update.max_bet.unwrap_or(casino.max_bet),
+        old_is_active: casino.is_active,
+        new_is_active: update.is_active.unwrap_or(casino.is_active),
+        old_switchboard_function: casino.switchboard_function,
+        new_switchboard_function: update.switchboard_function.unwrap_or(casino.switchboard_function),
+        old_oracle: casino.oracle,
+        new_oracle: update.oracle.unwrap_or(casino.oracle),
+        old_treasury_epoch_cap: casino.treasury_epoch_cap,
+        new_treasury_epoch_cap: update.treasury_epoch_cap.unwrap_or(casino.treasury_epoch_cap),
+        old_reserve_policy: casino.reserve_policy,
+        new_reserve_policy: update.reserve_policy.unwrap_or(casino.reserve_policy),
+        old_referral_share_bp: casino.referral_share_bp,
+        new_referral_share_bp: update.referral_share_bp.unwrap_or(casino.referral_share_bp),
+        old_auto_close_sessions: casino.auto_close_sessions,
+        new_auto_close_sessions: update.auto_close_sessions.unwrap_or(casino.auto_close_sessions),
+    };
+    
+    // Same limits as initialize, against the vault's current balance
+    if update.min_bet.is_some() || update.max_bet.is_some() {
+        casino.require_no_timelock()?;
+        CasinoConfig::validate_bet_limits(event.new_min_bet, event.new_max_bet, vault_balance)?;
+    }
+    
+    // Randomness providers are timelocked like the source itself
+    if update.oracle.is_some() || update.switchboard_function.is_some() {
+        casino.require_no_timelock()?;
+    }
+    
+    // Loosening a safeguard waits out the timelock too
+    if event.new_treasury_epoch_cap > event.old_treasury_epoch_cap
+        || event.new_reserve_policy.loosens(&event.old_reserve_policy)
+        || event.new_referral_share_bp > event.old_referral_share_bp
+    {
+        casino.require_no_timelock()?;
+    }
+    
+    event.new_reserve_policy.validate()?;
+    CasinoConfig::validate_referral_share(event.new_referral_share_bp)?;
+    
+    casino.min_bet = event.new_min_bet;
+    casino.max_bet = event.new_max_bet;
+    casino.is_active = event.new_is_active;
+    casino.switchboard_function = event.new_switchboard_function;
+    casino.oracle = event.new_oracle;
+    casino.treasury_epoch_cap = event.new_treasury_epoch_cap;
+    casino.reserve_policy = event.new_reserve_policy;
+    casino.referral_share_bp = event.new_referral_share_bp;
+    casino.auto_close_sessions = event.new_auto_close_sessions;
+    
+    msg!("Casino config updated");
+    msg!("Min bet: {} lamports", casino.min_bet);
+    msg!("Max bet: {} lamports", casino.max_bet);
+    msg!("Active: {}", casino.is_active);
+    
+    emit!(event);
+    
+    Ok(())
+}
+
+#[event]
+pub struct ConfigUpdated {
+    pub old_min_bet: u64,
+    pub new_min_bet: u64,
+    pub old_max_bet: u64,
+    pub new_max_bet: u64,
+    pub old_is_active: bool,
+    pub new_is_active: bool,
+    pub old_switchboard_function: Option<Pubkey>,
+    pub new_switchboard_function: Option<Pubkey>,
+    pub old_oracle: Pubkey,
+    pub new_oracle: Pubkey,
+    pub old_treasury_epoch_cap: u64,
+    pub new_treasury_epoch_cap: u64,
+    pub old_reserve_policy: ReservePolicy,
+    pub new_reserve_policy: ReservePolicy,
+    pub old_referral_share_bp: u64,
+    pub new_referral_share_bp: u64,
+    pub old_auto_close_sessions: bool,
+    pub new_auto_close_sessions: bool,
+}
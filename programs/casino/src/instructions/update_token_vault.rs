@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+pub struct UpdateTokenVault<'info> {
+    #[account(
+        seeds = [CASINO_SEED],
+        bump,
+        has_one = authority @ CasinoError::Unauthorized
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        mut,
+        seeds = [TOKEN_VAULT_SEED, casino.key().as_ref(), token_vault.mint.as_ref()],
+        bump = token_vault.bump,
+        has_one = vault_token_account,
+    )]
+    pub token_vault: Account<'info, TokenVault>,
+    
+    /// Read to validate max_bet against the bankroll
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    pub authority: Signer<'info>,
+}
+
+/// Fields to change; `None` leaves the current value untouched
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct TokenVaultUpdate {
+    pub min_bet: Option<u64>,
+    pub max_bet: Option<u64>,
+    pub is_active: Option<bool>,
+    /// `Some(None)` removes the allowlisted transfer hook program
+    pub transfer_hook_program: Option<Option<Pubkey>>,
+}
+
+pub fn handler(ctx: Context<UpdateTokenVault>, update: TokenVaultUpdate) -> Result<()> {
+    let token_vault = &mut ctx.accounts.token_vault;
+    
+    let min_bet = update.min_bet.unwrap_or(token_vault.min_bet);
+    let max_bet = update.max_bet.unwrap_or(token_vault.max_bet);
+    
+    if update.min_bet.is_some() || update.max_bet.is_some() {
+        CasinoConfig::validate_bet_limits(min_bet, max_bet, ctx.accounts.vault_token_account.amount)?;
+    }
+    
+    token_vault.min_bet = min_bet;
+    token_vault.max_bet = max_bet;
+    token_vault.is_active = update.is_active.unwrap_or(token_vault.is_active);
+    token_vault.transfer_hook_program = update.transfer_hook_program.unwrap_or(token_vault.transfer_hook_program);
+    
+    msg!("Token vault {} updated", token_vault.mint);
+    msg!("Min bet: {}, max bet: {}, active: {}", min_bet, max_bet, token_vault.is_active);
+    
+    emit!(TokenVaultUpdated {
+        mint: token_vault.mint,
+        min_bet,
+        max_bet,
+        is_active: token_vault.is_active,
+        transfer_hook_program: token_vault.transfer_hook_program,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct TokenVaultUpdated {
+    pub mint: Pubkey,
+    pub min_bet: u64,
+    pub max_bet: u64,
+    pub is_active: bool,
+    pub transfer_hook_program: Option<Pubkey>,
+}
@@ -0,0 +1,77 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+pub struct Withdraw<'info> {
+    #[account(
+        mut,
+        seeds = [CASINO_SEED],
+        bump,
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        mut,
+        seeds = [PLAYER_BALANCE_SEED, casino.key().as_ref(), player.key().as_ref()],
+        bump = player_balance.bump,
+    )]
+    pub player_balance: Account<'info, PlayerBalance>,
+    
+    #[account(
+        mut,
+        seeds = [VAULT_SEED, casino.key().as_ref()],
+        bump = casino.vault_bump,
+    )]
+    pub vault: SystemAccount<'info>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+/// Pay `amount` of the player's balance out of the vault. Allowed while paused.
+pub fn handler(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+    require!(amount > 0, CasinoError::InvalidWithdrawalAmount);
+    
+    let player_balance = &mut ctx.accounts.player_balance;
+    player_balance.debit(amount)?;
+    player_balance.total_withdrawn = player_balance.total_withdrawn
+        .checked_add(amount)
+        .ok_or(CasinoError::Overflow)?;
+    
+    let casino = &mut ctx.accounts.casino;
+    casino.remove_player_balance(amount)?;
+    
+    let casino_key = casino.key();
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.vault.to_account_info(),
+                to: ctx.accounts.player.to_account_info(),
+            },
+            &[&[VAULT_SEED, casino_key.as_ref(), &[casino.vault_bump]]],
+        ),
+        amount
+    )?;
+    
+    msg!("Withdrew {} lamports, balance {}", amount, player_balance.balance);
+    
+    emit!(BalanceWithdrawn {
+        player: player_balance.player,
+        amount,
+        balance: player_balance.balance,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct BalanceWithdrawn {
+    pub player: Pubkey,
+    pub amount: u64,
+    pub balance: u64,
+}
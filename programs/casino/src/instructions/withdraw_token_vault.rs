@@ -0,0 +1,116 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{Mint, TokenAccount, TokenInterface, TransferChecked};
+use crate::state::*;
+use crate::errors::CasinoError;
+use crate::token_extensions;
+
+#[derive(Accounts)]
+pub struct WithdrawTokenVault<'info> {
+    #[account(
+        seeds = [CASINO_SEED],
+        bump,
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    #[account(
+        mut,
+        seeds = [TOKEN_VAULT_SEED, casino.key().as_ref(), mint.key().as_ref()],
+        bump = token_vault.bump,
+        has_one = mint,
+        has_one = vault_token_account,
+    )]
+    pub token_vault: Account<'info, TokenVault>,
+    
+    pub mint: InterfaceAccount<'info, Mint>,
+    
+    #[account(mut)]
+    pub vault_token_account: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Allowlist entry for the owner of `destination`; withdrawals fail if it does not exist
+    #[account(
+        seeds = [TREASURY_DESTINATION_SEED, casino.key().as_ref(), treasury_destination.destination.as_ref()],
+        bump = treasury_destination.bump,
+    )]
+    pub treasury_destination: Account<'info, TreasuryDestination>,
+    
+    #[account(
+        mut,
+        token::mint = mint,
+        token::authority = treasury_destination.destination,
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+    
+    /// Treasurer role held by the signer
+    #[account(
+        seeds = [ROLE_SEED, casino.key().as_ref(), &Role::Treasurer.seed(), treasurer.key().as_ref()],
+        bump = treasurer_role.bump,
+    )]
+    pub treasurer_role: Account<'info, RoleAssignment>,
+    
+    pub treasurer: Signer<'info>,
+    
+    #[account(address = token_vault.token_program @ CasinoError::MintMismatch)]
+    pub token_program: Interface<'info, TokenInterface>,
+}
+
+/// Withdraw `amount` of a token vault's bankroll to a token account owned by
+/// an allowlisted treasury destination. The vault must still cover its
+/// pending and unclaimed payouts afterwards.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, WithdrawTokenVault<'info>>,
+    amount: u64,
+) -> Result<()> {
+    let token_vault = &mut ctx.accounts.token_vault;
+    
+    require!(amount > 0, CasinoError::InvalidWithdrawalAmount);
+    
+    let required_balance = amount
+        .checked_add(token_vault.total_liabilities()?)
+        .ok_or(CasinoError::Overflow)?;
+    require!(
+        ctx.accounts.vault_token_account.amount >= required_balance,
+        CasinoError::InsufficientVaultLiquidity
+    );
+    
+    token_extensions::validate_mint(&ctx.accounts.mint.to_account_info(), token_vault.transfer_hook_program)?;
+    
+    let casino_key = ctx.accounts.casino.key();
+    let mint_key = token_vault.mint;
+    
+    token_extensions::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.vault_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: token_vault.to_account_info(),
+            },
+            &[&[TOKEN_VAULT_SEED, casino_key.as_ref(), mint_key.as_ref(), &[token_vault.bump]]],
+        ).with_remaining_accounts(ctx.remaining_accounts.to_vec()),
+        amount,
+        ctx.accounts.mint.decimals,
+    )?;
+    
+    ctx.accounts.vault_token_account.reload()?;
+    let vault_balance_after = ctx.accounts.vault_token_account.amount;
+    
+    msg!("Withdrew {} from token vault {} to {}", amount, mint_key, ctx.accounts.destination.key());
+    
+    emit!(TokenVaultWithdrawn {
+        mint: mint_key,
+        destination: ctx.accounts.destination.key(),
+        amount,
+        vault_balance_after,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct TokenVaultWithdrawn {
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub vault_balance_after: u64,
+}
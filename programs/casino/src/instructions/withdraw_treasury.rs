@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use crate::state::*;
+use crate::errors::CasinoError;
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    #[account(
+        mut,
+        seeds = [CASINO_SEED],
+        bump,
+    )]
+    pub casino: Account<'info, CasinoConfig>,
+    
+    /// Treasury PDA that pays the withdrawal
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED, casino.key().as_ref()],
+        bump = casino.treasury_bump
+    )]
+    pub treasury: SystemAccount<'info>,
+    
+    /// Allowlist entry for the destination; withdrawals fail if it does not exist
+    #[account(
+        mut,
+        seeds = [TREASURY_DESTINATION_SEED, casino.key().as_ref(), destination.key().as_ref()],
+        bump = treasury_destination.bump,
+    )]
+    pub treasury_destination: Account<'info, TreasuryDestination>,
+    
+    /// CHECK: Only receives lamports; allowlisted via treasury_destination seeds
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+    
+    /// Treasurer role held by the signer
+    #[account(
+        seeds = [ROLE_SEED, casino.key().as_ref(), &Role::Treasurer.seed(), treasurer.key().as_ref()],
+        bump = treasurer_role.bump,
+    )]
+    pub treasurer_role: Account<'info, RoleAssignment>,
+    
+    pub treasurer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+    let casino = &mut ctx.accounts.casino;
+    let clock = Clock::get()?;
+    
+    require!(amount > 0, CasinoError::InvalidWithdrawalAmount);
+    
+    // The Treasury must stay rent-exempt
+    let treasury_balance = ctx.accounts.treasury.lamports();
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(0);
+    let required_balance = amount
+        .checked_add(rent_exempt_minimum)
+        .ok_or(CasinoError::Overflow)?;
+    require!(
+        treasury_balance >= required_balance,
+        CasinoError::InsufficientTreasuryFunds
+    );
+    
+    casino.record_treasury_withdrawal(amount, clock.epoch)?;
+    
+    let casino_key = casino.key();
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.treasury.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+            },
+            &[&[TREASURY_SEED, casino_key.as_ref(), &[casino.treasury_bump]]],
+        ),
+        amount
+    )?;
+    
+    let treasury_destination = &mut ctx.accounts.treasury_destination;
+    treasury_destination.total_withdrawn = treasury_destination.total_withdrawn
+        .checked_add(amount)
+        .ok_or(CasinoError::Overflow)?;
+    
+    let treasury_balance_after = ctx.accounts.treasury.lamports();
+    
+    msg!("Withdrew {} lamports from treasury to {}", amount, treasury_destination.destination);
+    msg!("Withdrawn this epoch: {} / {} lamports", casino.treasury_withdrawn_in_epoch, casino.treasury_epoch_cap);
+    
+    emit!(TreasuryWithdrawn {
+        destination: treasury_destination.destination,
+        amount,
+        epoch: clock.epoch,
+        withdrawn_in_epoch: casino.treasury_withdrawn_in_epoch,
+        treasury_balance_after,
+    });
+    
+    Ok(())
+}
+
+#[event]
+pub struct TreasuryWithdrawn {
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub epoch: u64,
+    pub withdrawn_in_epoch: u64,
+    pub treasury_balance_after: u64,
+}
@@ -4,7 +4,11 @@ declare_id!("CasytXe4cEiXJEcE3yZKxJtaHTDpZFkrVoe7ChJqY8ma");
 
 pub mod errors;
 pub mod instructions;
+pub mod outcome;
+pub mod randomness;
 pub mod state;
+pub mod token_extensions;
+pub mod utils;
 
 use instructions::*;
 
@@ -17,8 +21,9 @@ pub mod casino {
         min_bet: u64,
         max_bet: u64,
         initial_vault_amount: u64,
+        oracle: Pubkey,
     ) -> Result<()> {
-        instructions::initialize::handler(ctx, min_bet, max_bet, initial_vault_amount)
+        instructions::initialize::handler(ctx, min_bet, max_bet, initial_vault_amount, oracle)
     }
 
     pub fn place_bet(
@@ -26,15 +31,30 @@ pub mod casino {
         game_type: state::GameType,
         choice: u8,
         bet_amount: u64,
+        client_seed: Option<String>,
     ) -> Result<()> {
-        instructions::place_bet::handler(ctx, game_type, choice, bet_amount)
+        instructions::place_bet::handler(ctx, game_type, choice, bet_amount, client_seed)
     }
 
     pub fn fulfill_randomness(
         ctx: Context<FulfillRandomness>,
         random_value: [u8; 32],
+        server_seed: Option<String>,
     ) -> Result<()> {
-        instructions::fulfill_randomness::handler(ctx, random_value)
+        instructions::fulfill_randomness::handler(ctx, random_value, server_seed)
+    }
+    
+    pub fn settle_with_vrf(
+        ctx: Context<FulfillRandomness>,
+    ) -> Result<()> {
+        instructions::settle_with_vrf::handler(ctx)
+    }
+    
+    pub fn reveal_and_settle(
+        ctx: Context<FulfillRandomness>,
+        server_seed: String,
+    ) -> Result<()> {
+        instructions::reveal_and_settle::handler(ctx, server_seed)
     }
     
     pub fn claim_payout(
@@ -56,4 +76,296 @@ pub mod casino {
     ) -> Result<()> {
         instructions::skim_excess_to_treasury::handler(ctx, amount, min_vault_reserve)
     }
+    
+    pub fn commit_seed_chain(
+        ctx: Context<CommitSeedChain>,
+        commitment: [u8; 32],
+    ) -> Result<()> {
+        instructions::commit_seed_chain::handler(ctx, commitment)
+    }
+    
+    pub fn set_randomness_source(
+        ctx: Context<SetRandomnessSource>,
+        source: state::RandomnessSource,
+    ) -> Result<()> {
+        instructions::set_randomness_source::handler(ctx, source)
+    }
+    
+    pub fn update_config(
+        ctx: Context<UpdateConfig>,
+        update: ConfigUpdate,
+    ) -> Result<()> {
+        instructions::update_config::handler(ctx, update)
+    }
+    
+    pub fn propose_authority(
+        ctx: Context<ProposeAuthority>,
+        new_authority: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::propose_authority::handler(ctx, new_authority)
+    }
+    
+    pub fn accept_authority(
+        ctx: Context<AcceptAuthority>,
+    ) -> Result<()> {
+        instructions::accept_authority::handler(ctx)
+    }
+    
+    pub fn grant_role(
+        ctx: Context<GrantRole>,
+        role: state::Role,
+        member: Pubkey,
+    ) -> Result<()> {
+        instructions::grant_role::handler(ctx, role, member)
+    }
+    
+    pub fn revoke_role(
+        ctx: Context<RevokeRole>,
+    ) -> Result<()> {
+        instructions::revoke_role::handler(ctx)
+    }
+    
+    pub fn set_active(
+        ctx: Context<SetActive>,
+        is_active: bool,
+    ) -> Result<()> {
+        instructions::set_active::handler(ctx, is_active)
+    }
+    
+    pub fn queue_change(
+        ctx: Context<QueueChange>,
+        change: state::ConfigChange,
+    ) -> Result<()> {
+        instructions::queue_change::handler(ctx, change)
+    }
+    
+    pub fn execute_change(
+        ctx: Context<ExecuteChange>,
+    ) -> Result<()> {
+        instructions::execute_change::handler(ctx)
+    }
+    
+    pub fn cancel_change(
+        ctx: Context<CancelChange>,
+    ) -> Result<()> {
+        instructions::cancel_change::handler(ctx)
+    }
+    
+    pub fn add_treasury_destination(
+        ctx: Context<AddTreasuryDestination>,
+        destination: Pubkey,
+    ) -> Result<()> {
+        instructions::add_treasury_destination::handler(ctx, destination)
+    }
+    
+    pub fn remove_treasury_destination(
+        ctx: Context<RemoveTreasuryDestination>,
+    ) -> Result<()> {
+        instructions::remove_treasury_destination::handler(ctx)
+    }
+    
+    pub fn withdraw_treasury(
+        ctx: Context<WithdrawTreasury>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_treasury::handler(ctx, amount)
+    }
+    
+    pub fn crank_skim(
+        ctx: Context<CrankSkim>,
+    ) -> Result<()> {
+        instructions::crank_skim::handler(ctx)
+    }
+    
+    pub fn set_revenue_split(
+        ctx: Context<SetRevenueSplit>,
+        shares: Vec<state::BeneficiaryShare>,
+    ) -> Result<()> {
+        instructions::set_revenue_split::handler(ctx, shares)
+    }
+    
+    pub fn distribute_treasury<'info>(
+        ctx: Context<'_, '_, '_, 'info, DistributeTreasury<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::distribute_treasury::handler(ctx, amount)
+    }
+    
+    pub fn init_liquidity_pool(
+        ctx: Context<InitLiquidityPool>,
+    ) -> Result<()> {
+        instructions::init_liquidity_pool::handler(ctx)
+    }
+    
+    pub fn deposit_liquidity(
+        ctx: Context<DepositLiquidity>,
+        amount: u64,
+        min_shares: u64,
+    ) -> Result<()> {
+        instructions::deposit_liquidity::handler(ctx, amount, min_shares)
+    }
+    
+    pub fn process_deposit(
+        ctx: Context<ProcessDeposit>,
+    ) -> Result<()> {
+        instructions::process_deposit::handler(ctx)
+    }
+    
+    pub fn request_withdrawal(
+        ctx: Context<RequestWithdrawal>,
+        shares: u64,
+    ) -> Result<()> {
+        instructions::request_withdrawal::handler(ctx, shares)
+    }
+    
+    pub fn process_withdrawal(
+        ctx: Context<ProcessWithdrawal>,
+    ) -> Result<()> {
+        instructions::process_withdrawal::handler(ctx)
+    }
+    
+    pub fn cancel_withdrawal(
+        ctx: Context<CancelWithdrawal>,
+    ) -> Result<()> {
+        instructions::cancel_withdrawal::handler(ctx)
+    }
+    
+    pub fn init_token_vault<'info>(
+        ctx: Context<'_, '_, '_, 'info, InitTokenVault<'info>>,
+        min_bet: u64,
+        max_bet: u64,
+        initial_vault_amount: u64,
+        transfer_hook_program: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::init_token_vault::handler(ctx, min_bet, max_bet, initial_vault_amount, transfer_hook_program)
+    }
+    
+    pub fn update_token_vault(
+        ctx: Context<UpdateTokenVault>,
+        update: TokenVaultUpdate,
+    ) -> Result<()> {
+        instructions::update_token_vault::handler(ctx, update)
+    }
+    
+    pub fn place_token_bet<'info>(
+        ctx: Context<'_, '_, '_, 'info, PlaceTokenBet<'info>>,
+        game_type: state::GameType,
+        choice: u8,
+        bet_amount: u64,
+        client_seed: Option<String>,
+    ) -> Result<()> {
+        instructions::place_token_bet::handler(ctx, game_type, choice, bet_amount, client_seed)
+    }
+    
+    pub fn claim_token_payout<'info>(
+        ctx: Context<'_, '_, '_, 'info, ClaimTokenPayout<'info>>,
+    ) -> Result<()> {
+        instructions::claim_token_payout::handler(ctx)
+    }
+    
+    pub fn refund_expired_token<'info>(
+        ctx: Context<'_, '_, '_, 'info, RefundExpiredToken<'info>>,
+    ) -> Result<()> {
+        instructions::refund_expired_token::handler(ctx)
+    }
+    
+    pub fn deposit(
+        ctx: Context<Deposit>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::deposit::handler(ctx, amount)
+    }
+    
+    pub fn withdraw(
+        ctx: Context<Withdraw>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw::handler(ctx, amount)
+    }
+    
+    pub fn place_bet_from_balance(
+        ctx: Context<PlaceBetFromBalance>,
+        game_type: state::GameType,
+        choice: u8,
+        bet_amount: u64,
+        client_seed: Option<String>,
+    ) -> Result<()> {
+        instructions::place_bet_from_balance::handler(ctx, game_type, choice, bet_amount, client_seed)
+    }
+    
+    pub fn create_session_key(
+        ctx: Context<CreateSessionKey>,
+        signer: Pubkey,
+        expires_at: i64,
+        max_total_wager: u64,
+        allowed_games: Vec<state::GameType>,
+    ) -> Result<()> {
+        instructions::create_session_key::handler(ctx, signer, expires_at, max_total_wager, allowed_games)
+    }
+    
+    pub fn revoke_session_key(
+        ctx: Context<RevokeSessionKey>,
+    ) -> Result<()> {
+        instructions::revoke_session_key::handler(ctx)
+    }
+    
+    pub fn init_player(
+        ctx: Context<InitPlayer>,
+        referrer: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::init_player::handler(ctx, referrer)
+    }
+    
+    pub fn claim_referral_rewards(
+        ctx: Context<ClaimReferralRewards>,
+    ) -> Result<()> {
+        instructions::claim_referral_rewards::handler(ctx)
+    }
+    
+    pub fn set_vip_tiers(
+        ctx: Context<SetVipTiers>,
+        tiers: Vec<state::VipTier>,
+    ) -> Result<()> {
+        instructions::set_vip_tiers::handler(ctx, tiers)
+    }
+    
+    pub fn claim_rakeback(
+        ctx: Context<ClaimRakeback>,
+    ) -> Result<()> {
+        instructions::claim_rakeback::handler(ctx)
+    }
+    
+    pub fn set_player_limits(
+        ctx: Context<SetPlayerLimits>,
+        limits: state::LimitSet,
+    ) -> Result<()> {
+        instructions::set_player_limits::handler(ctx, limits)
+    }
+    
+    pub fn self_exclude(
+        ctx: Context<SelfExclude>,
+        duration: i64,
+    ) -> Result<()> {
+        instructions::self_exclude::handler(ctx, duration)
+    }
+    
+    pub fn close_session(
+        ctx: Context<CloseSession>,
+    ) -> Result<()> {
+        instructions::close_session::handler(ctx)
+    }
+    
+    pub fn mock_switchboard_randomness(
+        ctx: Context<MockSwitchboardRandomness>,
+        reveal: Option<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::mock_switchboard_randomness::handler(ctx, reveal)
+    }
+    
+    pub fn withdraw_token_vault<'info>(
+        ctx: Context<'_, '_, '_, 'info, WithdrawTokenVault<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_token_vault::handler(ctx, amount)
+    }
 }
@@ -0,0 +1,265 @@
+//! Game outcomes from a session's 32-byte random value.
+//!
+//! Derivation version 1 (recorded in `GameResult::derivation_version`):
+//!
+//! 1. The random value is expanded into a stream of 32-byte blocks. Block 0 is
+//!    the random value itself, block `k >= 1` is
+//!    `sha256(random_value || "casino-outcome-v1" || k as u32 LE)`.
+//! 2. Each block is read as four little-endian `u64` words, in order.
+//! 3. A draw in `0..n` takes words until one is below `u64::MAX - u64::MAX % n`
+//!    (the largest multiple of `n` that fits) and returns `word % n`. Rejected
+//!    words are skipped, so every value in `0..n` is exactly equally likely.
+//! 4. Games take their draws from the same stream in this order:
+//!    - CoinFlip: `side = draw(2)` (0 = heads, 1 = tails)
+//!    - Dice: `die1 = draw(6) + 1`, `die2 = draw(6) + 1`, outcome = sum
+//!    - Slots: `reel1 = draw(10)`, `reel2 = draw(10)`, `reel3 = draw(10)`,
+//!      outcome = `(reel1 * 100 + reel2 * 10 + reel3) % 256`
+//!
+//! Any change to these steps must bump `OUTCOME_DERIVATION_VERSION`.
+
+use anchor_lang::solana_program::hash::hashv;
+use crate::state::GameType;
+
+pub const OUTCOME_DERIVATION_VERSION: u8 = 1;
+
+const STREAM_DOMAIN: &[u8] = b"casino-outcome-v1";
+const WORDS_PER_BLOCK: usize = 4;
+
+/// Stream of uniformly distributed draws expanded from a random value
+pub struct OutcomeStream {
+    seed: [u8; 32],
+    block: [u8; 32],
+    block_index: u32,
+    word_index: usize,
+}
+
+impl OutcomeStream {
+    pub fn new(seed: &[u8; 32]) -> Self {
+        Self {
+            seed: *seed,
+            block: *seed,
+            block_index: 0,
+            word_index: 0,
+        }
+    }
+
+    fn next_word(&mut self) -> u64 {
+        if self.word_index == WORDS_PER_BLOCK {
+            self.block_index += 1;
+            self.block = hashv(&[&self.seed, STREAM_DOMAIN, &self.block_index.to_le_bytes()]).to_bytes();
+            self.word_index = 0;
+        }
+        let at = self.word_index * 8;
+        self.word_index += 1;
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&self.block[at..at + 8]);
+        u64::from_le_bytes(bytes)
+    }
+
+    /// Uniform draw in `0..n` by rejection sampling. `n` must be non-zero.
+    pub fn uniform(&mut self, n: u64) -> u64 {
+        let limit = u64::MAX - u64::MAX % n;
+        loop {
+            let word = self.next_word();
+            if word < limit {
+                return word % n;
+            }
+        }
+    }
+}
+
+/// Resolved outcome of one game round
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GameOutcome {
+    /// Game-specific outcome, see `GameResult::outcome`
+    pub outcome: u8,
+    pub is_win: bool,
+    /// Payout multiplier in basis points (0 on a loss)
+    pub payout_multiplier_bp: u64,
+}
+
+pub fn resolve(game_type: GameType, choice: u8, random_value: &[u8; 32]) -> GameOutcome {
+    let mut stream = OutcomeStream::new(random_value);
+
+    let (outcome, is_win) = match game_type {
+        GameType::CoinFlip => {
+            let side = stream.uniform(2) as u8;
+            (side, side == choice)
+        },
+        GameType::Dice => {
+            let die1 = stream.uniform(6) as u8 + 1;
+            let die2 = stream.uniform(6) as u8 + 1;
+            let sum = die1 + die2;
+            (sum, sum == choice)
+        },
+        GameType::Slots => {
+            let r1 = stream.uniform(10) as u16;
+            let r2 = stream.uniform(10) as u16;
+            let r3 = stream.uniform(10) as u16;
+            let packed = ((r1 * 100 + r2 * 10 + r3) % 256) as u8;
+            (packed, r1 == r2 && r2 == r3)
+        },
+    };
+
+    GameOutcome {
+        outcome,
+        is_win,
+        payout_multiplier_bp: if is_win { game_type.payout_multiplier_bp() } else { 0 },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLES: usize = 2_000_000;
+
+    /// Deterministic seeds so the statistical tests are reproducible
+    struct SplitMix64(u64);
+
+    impl SplitMix64 {
+        fn next(&mut self) -> u64 {
+            self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = self.0;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^ (z >> 31)
+        }
+
+        fn seed(&mut self) -> [u8; 32] {
+            let mut seed = [0u8; 32];
+            for chunk in seed.chunks_mut(8) {
+                chunk.copy_from_slice(&self.next().to_le_bytes());
+            }
+            seed
+        }
+    }
+
+    fn chi_square(counts: &[u64], expected: &[f64]) -> f64 {
+        counts
+            .iter()
+            .zip(expected)
+            .map(|(&observed, &expected)| {
+                let diff = observed as f64 - expected;
+                diff * diff / expected
+            })
+            .sum()
+    }
+
+    /// Chi-square critical values at p = 0.001 for the degrees of freedom used here
+    fn critical_value(degrees_of_freedom: usize) -> f64 {
+        match degrees_of_freedom {
+            1 => 10.828,
+            5 => 20.515,
+            9 => 27.877,
+            10 => 29.588,
+            35 => 66.619,
+            999 => 1143.917,
+            _ => unreachable!(),
+        }
+    }
+
+    fn assert_uniform(counts: &[u64], total: usize) {
+        let expected = vec![total as f64 / counts.len() as f64; counts.len()];
+        let statistic = chi_square(counts, &expected);
+        let critical = critical_value(counts.len() - 1);
+        assert!(statistic < critical, "chi-square {statistic} >= {critical} for {counts:?}");
+    }
+
+    #[test]
+    fn first_draw_is_uniform_across_seeds() {
+        for n in [2u64, 6, 10] {
+            let mut rng = SplitMix64(n);
+            let mut counts = vec![0u64; n as usize];
+            for _ in 0..SAMPLES {
+                counts[OutcomeStream::new(&rng.seed()).uniform(n) as usize] += 1;
+            }
+            assert_uniform(&counts, SAMPLES);
+        }
+    }
+
+    #[test]
+    fn expanded_stream_is_uniform() {
+        // A single seed drawn far past block 0 exercises the hash expansion
+        let mut stream = OutcomeStream::new(&[7u8; 32]);
+        let mut counts = vec![0u64; 11];
+        for _ in 0..SAMPLES {
+            counts[stream.uniform(11) as usize] += 1;
+        }
+        assert_uniform(&counts, SAMPLES);
+    }
+
+    #[test]
+    fn coin_flip_is_fair() {
+        let mut rng = SplitMix64(1);
+        let mut counts = [0u64; 2];
+        for _ in 0..SAMPLES {
+            counts[resolve(GameType::CoinFlip, 0, &rng.seed()).outcome as usize] += 1;
+        }
+        assert_uniform(&counts, SAMPLES);
+    }
+
+    #[test]
+    fn dice_pairs_are_uniform() {
+        let mut rng = SplitMix64(2);
+        let mut counts = vec![0u64; 36];
+        for _ in 0..SAMPLES {
+            let mut stream = OutcomeStream::new(&rng.seed());
+            let die1 = stream.uniform(6);
+            let die2 = stream.uniform(6);
+            counts[(die1 * 6 + die2) as usize] += 1;
+        }
+        assert_uniform(&counts, SAMPLES);
+    }
+
+    #[test]
+    fn dice_sums_follow_two_dice_distribution() {
+        let mut rng = SplitMix64(3);
+        let mut counts = vec![0u64; 11];
+        for _ in 0..SAMPLES {
+            counts[resolve(GameType::Dice, 7, &rng.seed()).outcome as usize - 2] += 1;
+        }
+        let expected: Vec<f64> = (2..=12)
+            .map(|sum: i32| (6 - (sum - 7).abs()) as f64 / 36.0 * SAMPLES as f64)
+            .collect();
+        assert!(chi_square(&counts, &expected) < critical_value(10));
+    }
+
+    #[test]
+    fn slot_reels_are_uniform() {
+        let mut rng = SplitMix64(4);
+        let mut counts = vec![0u64; 1000];
+        for _ in 0..SAMPLES {
+            let mut stream = OutcomeStream::new(&rng.seed());
+            let reels = stream.uniform(10) * 100 + stream.uniform(10) * 10 + stream.uniform(10);
+            counts[reels as usize] += 1;
+        }
+        assert_uniform(&counts, SAMPLES);
+    }
+
+    #[test]
+    fn words_in_the_biased_zone_are_rejected() {
+        let mut seed = [0u8; 32];
+        seed[..8].copy_from_slice(&u64::MAX.to_le_bytes());
+        seed[8..16].copy_from_slice(&7u64.to_le_bytes());
+        // u64::MAX % 6 == 3, so u64::MAX is rejected and the next word is used
+        assert_eq!(OutcomeStream::new(&seed).uniform(6), 1);
+    }
+
+    #[test]
+    fn derivation_v1_test_vector() {
+        let mut seed = [0u8; 32];
+        for (i, byte) in seed.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+        // Words of block 0: 0x0706050403020100, 0x0f0e0d0c0b0a0908, ...
+        assert_eq!(resolve(GameType::CoinFlip, 0, &seed).outcome, 0);
+        assert_eq!(
+            resolve(GameType::Dice, 2, &seed).outcome,
+            (0x0706050403020100u64 % 6 + 1 + 0x0f0e0d0c0b0a0908u64 % 6 + 1) as u8
+        );
+        let slots = resolve(GameType::Slots, 0, &seed);
+        let reels = [0x0706050403020100u64, 0x0f0e0d0c0b0a0908, 0x1716151413121110].map(|w| w % 10);
+        assert_eq!(slots.outcome, ((reels[0] * 100 + reels[1] * 10 + reels[2]) % 256) as u8);
+    }
+}
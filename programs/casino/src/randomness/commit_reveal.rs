@@ -0,0 +1,152 @@
+use anchor_lang::prelude::*;
+use crate::errors::CasinoError;
+use crate::state::{RandomnessRequest, SeedChain};
+use crate::utils::{generate_outcome, next_commitment, verify_server_seed_hash};
+
+/// Check a revealed server seed against the commitment the session is bound to
+/// and derive its random value. The first reveal of a seed rotates the chain to
+/// the next commitment; later sessions bound to the same seed can then be
+/// settled by anyone with it.
+pub fn reveal(
+    seed_chain: &mut SeedChain,
+    request: &RandomnessRequest,
+    nonce: u64,
+    server_seed: &str,
+) -> Result<[u8; 32]> {
+    let server_seed_hash = request.server_seed_hash
+        .ok_or(CasinoError::InvalidRandomnessCallback)?;
+    
+    require!(
+        verify_server_seed_hash(server_seed, &server_seed_hash),
+        CasinoError::InvalidServerSeed
+    );
+    
+    if seed_chain.commitment == server_seed_hash {
+        seed_chain.commitment = next_commitment(server_seed)
+            .ok_or(CasinoError::InvalidServerSeed)?;
+        seed_chain.revealed_count = seed_chain.revealed_count
+            .checked_add(1)
+            .ok_or(CasinoError::Overflow)?;
+        
+        emit!(ServerSeedRevealed {
+            chain_id: seed_chain.chain_id,
+            index: seed_chain.revealed_count,
+            server_seed: server_seed.to_string(),
+            next_commitment: seed_chain.commitment,
+        });
+    }
+    
+    msg!("Server seed revealed: {}", server_seed);
+    
+    Ok(generate_outcome(server_seed, &request.client_seed, nonce))
+}
+
+#[event]
+pub struct ServerSeedRevealed {
+    pub chain_id: u64,
+    /// 1-based position of the seed in its chain
+    pub index: u64,
+    pub server_seed: String,
+    pub next_commitment: [u8; 32],
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::hash::hash;
+    use crate::state::RandomnessSource;
+    use crate::utils::hash_server_seed;
+
+    const CLIENT_SEED: &str = "lucky";
+
+    fn hex(bytes: &[u8]) -> String {
+        bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+    }
+
+    /// Seeds `s_1 .. s_len` in reveal order, with `s_{i-1} = hex(sha256(s_i))`
+    fn chain(len: usize) -> Vec<String> {
+        let mut seeds = vec![hex(&[0xab; 32])];
+        while seeds.len() < len {
+            let previous = hex(&hash(seeds[0].as_bytes()).to_bytes());
+            seeds.insert(0, previous);
+        }
+        seeds
+    }
+
+    fn seed_chain(first_seed: &str) -> SeedChain {
+        SeedChain {
+            commitment: hash_server_seed(first_seed),
+            chain_id: 1,
+            revealed_count: 0,
+            bump: 0,
+        }
+    }
+
+    fn request(commitment: [u8; 32]) -> RandomnessRequest {
+        RandomnessRequest {
+            source: RandomnessSource::CommitReveal,
+            request_id: String::new(),
+            switchboard_request: None,
+            commit_slot: 0,
+            oracle: None,
+            oracle_signature_hash: None,
+            server_seed_hash: Some(commitment),
+            client_seed: CLIENT_SEED.to_string(),
+        }
+    }
+
+    #[test]
+    fn reveals_a_chain_in_order() {
+        let seeds = chain(4);
+        let mut seed_chain = seed_chain(&seeds[0]);
+
+        for (nonce, seed) in seeds.iter().enumerate() {
+            let bound = request(seed_chain.commitment);
+            let value = reveal(&mut seed_chain, &bound, nonce as u64, seed).unwrap();
+
+            assert_eq!(value, generate_outcome(seed, CLIENT_SEED, nonce as u64));
+            assert_eq!(seed_chain.revealed_count, nonce as u64 + 1);
+            if let Some(next_seed) = seeds.get(nonce + 1) {
+                assert_eq!(seed_chain.commitment, hash_server_seed(next_seed));
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_a_seed_that_does_not_match_the_commitment() {
+        let seeds = chain(2);
+        let mut seed_chain = seed_chain(&seeds[0]);
+        let bound = request(seed_chain.commitment);
+
+        assert_eq!(
+            reveal(&mut seed_chain, &bound, 0, &seeds[1]).unwrap_err(),
+            CasinoError::InvalidServerSeed.into()
+        );
+        assert_eq!(seed_chain.commitment, hash_server_seed(&seeds[0]));
+        assert_eq!(seed_chain.revealed_count, 0);
+    }
+
+    #[test]
+    fn later_reveals_of_a_seed_do_not_rotate_again() {
+        let seeds = chain(2);
+        let mut seed_chain = seed_chain(&seeds[0]);
+        let first = request(seed_chain.commitment);
+        let second = request(seed_chain.commitment);
+
+        reveal(&mut seed_chain, &first, 0, &seeds[0]).unwrap();
+        let value = reveal(&mut seed_chain, &second, 1, &seeds[0]).unwrap();
+
+        assert_eq!(value, generate_outcome(&seeds[0], CLIENT_SEED, 1));
+        assert_eq!(seed_chain.commitment, hash_server_seed(&seeds[1]));
+        assert_eq!(seed_chain.revealed_count, 1);
+    }
+
+    #[test]
+    fn next_commitment_decodes_lowercase_hex_only() {
+        let seed = hex(&[0x5a; 32]);
+        assert_eq!(next_commitment(&seed), Some([0x5a; 32]));
+        assert_eq!(next_commitment(&seed.to_uppercase()), None);
+        assert_eq!(next_commitment(&seed[..62]), None);
+        assert_eq!(next_commitment(&format!("{}zz", &seed[..62])), None);
+    }
+}
@@ -0,0 +1,4 @@
+pub mod commit_reveal;
+pub mod oracle;
+pub mod slot_hash;
+pub mod switchboard;
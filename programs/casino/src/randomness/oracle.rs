@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::hash::{hash, hashv};
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+use crate::errors::CasinoError;
+
+/// Size of the header of an Ed25519 program instruction (num_signatures + padding)
+const ED25519_HEADER_LEN: usize = 2;
+/// Size of one `Ed25519SignatureOffsets` entry
+const ED25519_OFFSETS_LEN: usize = 14;
+const PUBKEY_LEN: usize = 32;
+const SIGNATURE_LEN: usize = 64;
+
+/// Message the oracle signs for a session: session PDA || game_id (LE) || created slot (LE)
+pub const ORACLE_MESSAGE_LEN: usize = 32 + 8 + 8;
+
+pub fn oracle_message(session: &Pubkey, game_id: u64, created_slot: u64) -> [u8; ORACLE_MESSAGE_LEN] {
+    let mut message = [0u8; ORACLE_MESSAGE_LEN];
+    message[..32].copy_from_slice(session.as_ref());
+    message[32..40].copy_from_slice(&game_id.to_le_bytes());
+    message[40..].copy_from_slice(&created_slot.to_le_bytes());
+    message
+}
+
+/// Verify that the instruction immediately before the current one is an Ed25519
+/// program instruction carrying a single signature by `oracle` over `message`,
+/// and return the hash of that signature.
+///
+/// The Ed25519 program has already checked the signature itself (the transaction
+/// would fail otherwise); here we only make sure it signed what we expect.
+/// The signature alone is NOT a safe random value: the verifier cannot check
+/// the signer's nonce, so the oracle can produce many valid signatures over the
+/// same message and pick one. Settlement therefore locks the first signature
+/// in and mixes it with the hash of the slot it landed in (see `randomness`),
+/// which the oracle cannot know when signing.
+pub fn verified_signature_hash(
+    instructions_sysvar: &AccountInfo,
+    oracle: &Pubkey,
+    message: &[u8],
+) -> Result<[u8; 32]> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    require!(current_index > 0, CasinoError::InvalidRandomnessCallback);
+
+    let ix = load_instruction_at_checked((current_index - 1) as usize, instructions_sysvar)?;
+    require!(ix.program_id == ed25519_program::ID, CasinoError::InvalidRandomnessCallback);
+    require!(ix.accounts.is_empty(), CasinoError::InvalidRandomnessCallback);
+
+    let data = &ix.data;
+    require!(
+        data.len() >= ED25519_HEADER_LEN + ED25519_OFFSETS_LEN && data[0] == 1,
+        CasinoError::InvalidRandomnessCallback
+    );
+
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+    let offsets = ED25519_HEADER_LEN;
+    let signature_offset = read_u16(offsets) as usize;
+    let signature_ix_index = read_u16(offsets + 2);
+    let pubkey_offset = read_u16(offsets + 4) as usize;
+    let pubkey_ix_index = read_u16(offsets + 6);
+    let message_offset = read_u16(offsets + 8) as usize;
+    let message_size = read_u16(offsets + 10) as usize;
+    let message_ix_index = read_u16(offsets + 12);
+
+    // All data must live inside the Ed25519 instruction itself, otherwise the
+    // program could have verified bytes from some other instruction.
+    require!(
+        signature_ix_index == u16::MAX && pubkey_ix_index == u16::MAX && message_ix_index == u16::MAX,
+        CasinoError::InvalidRandomnessCallback
+    );
+
+    let pubkey = data
+        .get(pubkey_offset..pubkey_offset + PUBKEY_LEN)
+        .ok_or(CasinoError::InvalidRandomnessCallback)?;
+    let signature = data
+        .get(signature_offset..signature_offset + SIGNATURE_LEN)
+        .ok_or(CasinoError::InvalidRandomnessCallback)?;
+    let signed_message = data
+        .get(message_offset..message_offset + message_size)
+        .ok_or(CasinoError::InvalidRandomnessCallback)?;
+
+    require!(pubkey == oracle.as_ref(), CasinoError::InvalidRandomnessCallback);
+    require!(signed_message == message, CasinoError::InvalidRandomnessCallback);
+
+    Ok(hash(signature).to_bytes())
+}
+
+/// Random value of a session whose signature hash was locked in at `signature_slot`
+pub fn randomness(slot_hashes: &AccountInfo, signature_hash: &[u8; 32], signature_slot: u64) -> Result<[u8; 32]> {
+    let slot_hash = super::slot_hash::slot_hash(slot_hashes, signature_slot)?;
+    Ok(hashv(&[signature_hash, &slot_hash]).to_bytes())
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::errors::CasinoError;
+
+const ENTRY_LEN: usize = 8 + 32;
+
+/// Random value from the bank hash of the slot the bet landed in, mixed with
+/// the session address.
+///
+/// The bettor cannot know that hash when the bet is placed, but the leader of
+/// the slot can influence it, so this source is only suitable for low stakes.
+/// SlotHashes keeps the last 512 slots; sessions have to be settled before then
+/// and are only refunded after.
+pub fn randomness(slot_hashes: &AccountInfo, slot: u64, session: &Pubkey) -> Result<[u8; 32]> {
+    let slot_hash = slot_hash(slot_hashes, slot)?;
+    Ok(hashv(&[&slot_hash, session.as_ref()]).to_bytes())
+}
+
+/// Bank hash of `slot` from the SlotHashes sysvar. Fails with
+/// `RandomnessNotRevealed` until the slot is complete and with
+/// `SlotHashUnavailable` once it has left the 512-slot window.
+pub fn slot_hash(slot_hashes: &AccountInfo, slot: u64) -> Result<[u8; 32]> {
+    let data = slot_hashes.try_borrow_data()?;
+    require!(data.len() >= 8, CasinoError::InvalidRandomnessCallback);
+    
+    let mut len_bytes = [0u8; 8];
+    len_bytes.copy_from_slice(&data[..8]);
+    let len = (u64::from_le_bytes(len_bytes) as usize).min((data.len() - 8) / ENTRY_LEN);
+    
+    let slot_at = |i: usize| {
+        let at = 8 + i * ENTRY_LEN;
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&data[at..at + 8]);
+        u64::from_le_bytes(bytes)
+    };
+    
+    require!(len > 0 && slot_at(0) >= slot, CasinoError::RandomnessNotRevealed);
+    
+    // Entries are sorted by slot, newest first
+    let (mut lo, mut hi) = (0usize, len);
+    while lo < hi {
+        let mid = (lo + hi) / 2;
+        if slot_at(mid) > slot {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    require!(lo < len && slot_at(lo) == slot, CasinoError::SlotHashUnavailable);
+    
+    let at = 8 + lo * ENTRY_LEN + 8;
+    let mut hash = [0u8; 32];
+    hash.copy_from_slice(&data[at..at + 32]);
+    Ok(hash)
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::errors::CasinoError;
+
+/// Switchboard On-Demand program IDs (randomness accounts are owned by one of these)
+/// SBondMDrcV3K4kxZR1HNVT7osZxAHVHgYXL5Ze1oMUv
+pub const ON_DEMAND_MAINNET_PID: Pubkey = Pubkey::new_from_array([
+    6, 115, 189, 70, 242, 228, 126, 4, 241, 43, 217, 47, 183, 49, 150, 142,
+    205, 157, 151, 87, 194, 116, 218, 135, 71, 111, 70, 92, 4, 12, 101, 115,
+]);
+/// Aio4gaXjXzJNVLtzwtNVmSqGKpANtXhybbkhtAC94ji2
+pub const ON_DEMAND_DEVNET_PID: Pubkey = Pubkey::new_from_array([
+    144, 110, 20, 100, 197, 248, 183, 99, 60, 192, 90, 66, 76, 221, 179, 174,
+    205, 109, 171, 184, 174, 199, 71, 188, 79, 62, 17, 48, 30, 64, 99, 203,
+]);
+
+/// Anchor discriminator of `RandomnessAccountData`
+pub const RANDOMNESS_DISCRIMINATOR: [u8; 8] = [10, 66, 229, 135, 220, 239, 217, 114];
+
+// Byte offsets into the account data (after the 8-byte discriminator):
+// authority (32) | queue (32) | seed_slothash (32) | seed_slot (8) | oracle (32)
+// | reveal_slot (8) | value (32) | reserved (224)
+const SEED_SLOT_OFFSET: usize = 8 + 32 + 32 + 32;
+const REVEAL_SLOT_OFFSET: usize = SEED_SLOT_OFFSET + 8 + 32;
+const VALUE_OFFSET: usize = REVEAL_SLOT_OFFSET + 8;
+pub const RANDOMNESS_ACCOUNT_LEN: usize = VALUE_OFFSET + 32 + 96 + 128;
+
+/// How many slots a commitment may be behind the bet. The commit usually
+/// lands a slot or two before the bet; older commitments are rejected.
+pub const MAX_COMMIT_AGE_SLOTS: u64 = 10;
+
+/// The fields of a Switchboard On-Demand randomness account we rely on.
+///
+/// Parsed by hand rather than through the Switchboard SDK, which does not
+/// build against anchor 0.28.
+pub struct SwitchboardRandomness {
+    /// Slot whose slothash seeds the randomness (set by `commit`)
+    pub seed_slot: u64,
+    /// Slot the oracle revealed the value in (0 or stale until `reveal`)
+    pub reveal_slot: u64,
+    /// Revealed random value
+    pub value: [u8; 32],
+}
+
+impl SwitchboardRandomness {
+    pub fn load(account: &AccountInfo) -> Result<Self> {
+        // `mock-vrf` builds also accept the fake accounts written by
+        // `mock_switchboard_randomness`
+        require!(
+            *account.owner == ON_DEMAND_MAINNET_PID
+                || *account.owner == ON_DEMAND_DEVNET_PID
+                || (cfg!(feature = "mock-vrf") && *account.owner == crate::ID),
+            CasinoError::InvalidRandomnessAccount
+        );
+
+        let data = account.try_borrow_data()?;
+        require!(
+            data.len() >= RANDOMNESS_ACCOUNT_LEN && data[..8] == RANDOMNESS_DISCRIMINATOR,
+            CasinoError::InvalidRandomnessAccount
+        );
+
+        let read_u64 = |at: usize| {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(&data[at..at + 8]);
+            u64::from_le_bytes(bytes)
+        };
+        let mut value = [0u8; 32];
+        value.copy_from_slice(&data[VALUE_OFFSET..VALUE_OFFSET + 32]);
+
+        Ok(Self {
+            seed_slot: read_u64(SEED_SLOT_OFFSET),
+            reveal_slot: read_u64(REVEAL_SLOT_OFFSET),
+            value,
+        })
+    }
+
+    /// Write the fields back in Switchboard's layout (used for localnet fakes)
+    pub fn store(&self, data: &mut [u8]) -> Result<()> {
+        require!(
+            data.len() >= RANDOMNESS_ACCOUNT_LEN,
+            CasinoError::InvalidRandomnessAccount
+        );
+        data[..8].copy_from_slice(&RANDOMNESS_DISCRIMINATOR);
+        data[SEED_SLOT_OFFSET..SEED_SLOT_OFFSET + 8].copy_from_slice(&self.seed_slot.to_le_bytes());
+        data[REVEAL_SLOT_OFFSET..REVEAL_SLOT_OFFSET + 8].copy_from_slice(&self.reveal_slot.to_le_bytes());
+        data[VALUE_OFFSET..VALUE_OFFSET + 32].copy_from_slice(&self.value);
+        Ok(())
+    }
+
+    /// At bet time the account must have been committed within the last
+    /// `MAX_COMMIT_AGE_SLOTS` slots and not revealed since, so nobody can know
+    /// the value when the bet is placed.
+    pub fn require_fresh_commit(&self, current_slot: u64) -> Result<()> {
+        require!(
+            self.seed_slot < current_slot
+                && current_slot - self.seed_slot <= MAX_COMMIT_AGE_SLOTS,
+            CasinoError::StaleRandomnessCommitment
+        );
+        require!(
+            self.reveal_slot <= self.seed_slot,
+            CasinoError::StaleRandomnessCommitment
+        );
+        Ok(())
+    }
+
+    /// At settlement the account must still carry the commitment bound to the
+    /// session and the oracle must have revealed it. The value is mixed with the
+    /// session address, so sessions sharing an account get independent outcomes.
+    pub fn revealed_value(&self, commit_slot: u64, session: &Pubkey) -> Result<[u8; 32]> {
+        require!(
+            self.seed_slot == commit_slot,
+            CasinoError::InvalidRandomnessAccount
+        );
+        require!(
+            self.reveal_slot > self.seed_slot,
+            CasinoError::RandomnessNotRevealed
+        );
+        Ok(hashv(&[&self.value, session.as_ref()]).to_bytes())
+    }
+}
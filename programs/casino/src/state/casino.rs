@@ -1,9 +1,15 @@
 use anchor_lang::prelude::*;
+use super::{
+    GameType, RandomnessSource, BASIS_POINTS, DEFAULT_CRANK_TIP_BP, DEFAULT_RESERVE_FLOOR,
+    DEFAULT_RESERVE_MAX_BET_MULTIPLE, MAX_CRANK_TIP_BP, MAX_REFERRAL_SHARE_BP,
+};
 
 #[account]
 #[derive(InitSpace)]
 pub struct CasinoConfig {
     pub authority: Pubkey,
+    /// Proposed new authority, set by `propose_authority` until it signs `accept_authority`
+    pub pending_authority: Option<Pubkey>,
     pub vault_bump: u8,
     pub treasury_bump: u8,
     pub min_bet: u64,
@@ -14,6 +20,85 @@ pub struct CasinoConfig {
     pub total_treasury_skimmed: u64,
     pub is_active: bool,
     pub switchboard_function: Option<Pubkey>,
+    /// Ed25519 key whose signature over a session seeds its randomness
+    pub oracle: Pubkey,
+    /// Randomness source used for new bets
+    pub randomness_source: RandomnessSource,
+    /// Sum of the maximum payouts of all pending sessions
+    pub pending_liability: u64,
+    /// Sum of resolved wins not yet claimed (`payout_claimed == false`)
+    pub unclaimed_payouts: u64,
+    /// Sum of all `PlayerBalance` balances, held in the vault
+    pub player_balances: u64,
+    /// Seconds a queued change must wait before it can be executed.
+    /// While non-zero, limits, authority and randomness source can only change through the timelock.
+    pub timelock_delay: i64,
+    /// ID of the next queued `PendingChange`
+    pub next_change_id: u64,
+    /// Max lamports `withdraw_treasury` may move out of the Treasury per epoch
+    pub treasury_epoch_cap: u64,
+    /// Epoch `treasury_withdrawn_in_epoch` refers to
+    pub treasury_epoch: u64,
+    pub treasury_withdrawn_in_epoch: u64,
+    pub total_treasury_withdrawn: u64,
+    /// Reserve kept in the vault above liabilities; `crank_skim` only moves the excess
+    pub reserve_policy: ReservePolicy,
+    /// LP share mint once `init_liquidity_pool` has run. Vault profit then
+    /// belongs to the LPs, so skims to the treasury are disabled.
+    pub lp_mint: Option<Pubkey>,
+    /// Share of the house edge of each referred bet accrued to the referrer, in basis points
+    pub referral_share_bp: u64,
+    /// Sum of accrued, unclaimed referral rewards, held in the vault
+    pub referral_rewards: u64,
+    /// Sum of accrued, unclaimed VIP rakeback, held in the vault
+    pub rakeback: u64,
+    /// Close sessions at settlement when nothing is left to claim; the
+    /// result survives in the `SessionSettled` event
+    pub auto_close_sessions: bool,
+}
+
+/// Vault reserve on top of outstanding liabilities. The largest of the three
+/// components applies; a zero component is ignored.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct ReservePolicy {
+    /// Absolute reserve in lamports
+    pub floor: u64,
+    /// Reserve as a multiple of `max_bet`
+    pub max_bet_multiple: u64,
+    /// Reserve as a percentage of liabilities, in basis points
+    pub liability_bp: u64,
+    /// Share of each crank skim paid to the cranker, in basis points
+    pub crank_tip_bp: u64,
+}
+
+impl Default for ReservePolicy {
+    fn default() -> Self {
+        Self {
+            floor: DEFAULT_RESERVE_FLOOR,
+            max_bet_multiple: DEFAULT_RESERVE_MAX_BET_MULTIPLE,
+            liability_bp: 0,
+            crank_tip_bp: DEFAULT_CRANK_TIP_BP,
+        }
+    }
+}
+
+impl ReservePolicy {
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.crank_tip_bp <= MAX_CRANK_TIP_BP,
+            crate::errors::CasinoError::InvalidReservePolicy
+        );
+        Ok(())
+    }
+    
+    /// Whether switching from `current` to this policy lowers any reserve or
+    /// raises the crank tip
+    pub fn loosens(&self, current: &ReservePolicy) -> bool {
+        self.floor < current.floor
+            || self.max_bet_multiple < current.max_bet_multiple
+            || self.liability_bp < current.liability_bp
+            || self.crank_tip_bp > current.crank_tip_bp
+    }
 }
 
 impl CasinoConfig {
@@ -21,6 +106,203 @@ impl CasinoConfig {
         self.is_active
     }
     
+    /// Mock randomness lets the caller pick the outcome, so only builds with
+    /// the `mock-vrf` feature (localnet) accept it
+    pub fn validate_randomness_source(source: RandomnessSource) -> Result<()> {
+        require!(
+            source != RandomnessSource::Mock || cfg!(feature = "mock-vrf"),
+            crate::errors::CasinoError::MockVRFNotAllowed
+        );
+        Ok(())
+    }
+    
+    /// Everything the vault owes: pending sessions at their maximum payout,
+    /// unclaimed wins, player balances, referral rewards and rakeback
+    pub fn total_liabilities(&self) -> Result<u64> {
+        self.pending_liability
+            .checked_add(self.unclaimed_payouts)
+            .and_then(|total| total.checked_add(self.player_balances))
+            .and_then(|total| total.checked_add(self.referral_rewards))
+            .and_then(|total| total.checked_add(self.rakeback))
+            .ok_or(error!(crate::errors::CasinoError::Overflow))
+    }
+    
+    /// What the vault is worth to LPs: its balance minus liabilities and the
+    /// rent-exempt minimum it must keep
+    pub fn net_asset_value(&self, vault_balance: u64, rent_exempt_minimum: u64) -> Result<u64> {
+        let reserved = self.total_liabilities()?
+            .checked_add(rent_exempt_minimum)
+            .ok_or(crate::errors::CasinoError::Overflow)?;
+        Ok(vault_balance.saturating_sub(reserved))
+    }
+    
+    pub fn require_no_liquidity_pool(&self) -> Result<()> {
+        require!(self.lp_mint.is_none(), crate::errors::CasinoError::LiquidityPoolActive);
+        Ok(())
+    }
+    
+    /// Vault balance that `crank_skim` must leave behind: liabilities plus the
+    /// reserve policy plus the vault's rent-exempt minimum
+    pub fn required_vault_balance(&self, rent_exempt_minimum: u64) -> Result<u64> {
+        let liabilities = self.total_liabilities()?;
+        let policy = &self.reserve_policy;
+        
+        let max_bet_reserve = self.max_bet
+            .checked_mul(policy.max_bet_multiple)
+            .ok_or(crate::errors::CasinoError::Overflow)?;
+        let liability_reserve = (liabilities as u128)
+            .checked_mul(policy.liability_bp as u128)
+            .ok_or(crate::errors::CasinoError::Overflow)?
+            / BASIS_POINTS as u128;
+        let liability_reserve = u64::try_from(liability_reserve)
+            .map_err(|_| crate::errors::CasinoError::Overflow)?;
+        
+        let reserve = policy.floor.max(max_bet_reserve).max(liability_reserve);
+        
+        liabilities
+            .checked_add(reserve)
+            .and_then(|balance| balance.checked_add(rent_exempt_minimum))
+            .ok_or(error!(crate::errors::CasinoError::Overflow))
+    }
+    
+    /// Reserve a new bet's maximum payout, making sure the vault (already
+    /// holding the bet) can cover it together with all other liabilities
+    /// without touching its rent-exempt minimum
+    pub fn reserve_liability(
+        &mut self,
+        max_payout: u64,
+        vault_balance: u64,
+        rent_exempt_minimum: u64,
+    ) -> Result<()> {
+        let required_balance = self.total_liabilities()?
+            .checked_add(max_payout)
+            .and_then(|balance| balance.checked_add(rent_exempt_minimum))
+            .ok_or(crate::errors::CasinoError::Overflow)?;
+        require!(
+            vault_balance >= required_balance,
+            crate::errors::CasinoError::InsufficientVaultLiquidity
+        );
+        self.pending_liability = self.pending_liability
+            .checked_add(max_payout)
+            .ok_or(crate::errors::CasinoError::Overflow)?;
+        Ok(())
+    }
+    
+    /// Release a session's reservation once it is settled or refunded
+    pub fn release_liability(&mut self, max_payout: u64) -> Result<()> {
+        self.pending_liability = self.pending_liability
+            .checked_sub(max_payout)
+            .ok_or(crate::errors::CasinoError::Overflow)?;
+        Ok(())
+    }
+    
+    pub fn add_unclaimed_payout(&mut self, payout: u64) -> Result<()> {
+        self.unclaimed_payouts = self.unclaimed_payouts
+            .checked_add(payout)
+            .ok_or(crate::errors::CasinoError::Overflow)?;
+        Ok(())
+    }
+    
+    pub fn remove_unclaimed_payout(&mut self, payout: u64) -> Result<()> {
+        self.unclaimed_payouts = self.unclaimed_payouts
+            .checked_sub(payout)
+            .ok_or(crate::errors::CasinoError::Overflow)?;
+        Ok(())
+    }
+    
+    pub fn add_player_balance(&mut self, amount: u64) -> Result<()> {
+        self.player_balances = self.player_balances
+            .checked_add(amount)
+            .ok_or(crate::errors::CasinoError::Overflow)?;
+        Ok(())
+    }
+    
+    pub fn remove_player_balance(&mut self, amount: u64) -> Result<()> {
+        self.player_balances = self.player_balances
+            .checked_sub(amount)
+            .ok_or(crate::errors::CasinoError::Overflow)?;
+        Ok(())
+    }
+    
+    /// Referral reward for a settled bet: `referral_share_bp` of its theoretical house edge
+    pub fn referral_reward(&self, game_type: GameType, choice: u8, bet_amount: u64) -> Result<u64> {
+        game_type.house_edge_share(choice, bet_amount, self.referral_share_bp)
+    }
+    
+    pub fn add_referral_reward(&mut self, amount: u64) -> Result<()> {
+        self.referral_rewards = self.referral_rewards
+            .checked_add(amount)
+            .ok_or(crate::errors::CasinoError::Overflow)?;
+        Ok(())
+    }
+    
+    pub fn remove_referral_reward(&mut self, amount: u64) -> Result<()> {
+        self.referral_rewards = self.referral_rewards
+            .checked_sub(amount)
+            .ok_or(crate::errors::CasinoError::Overflow)?;
+        Ok(())
+    }
+    
+    pub fn add_rakeback(&mut self, amount: u64) -> Result<()> {
+        self.rakeback = self.rakeback
+            .checked_add(amount)
+            .ok_or(crate::errors::CasinoError::Overflow)?;
+        Ok(())
+    }
+    
+    pub fn remove_rakeback(&mut self, amount: u64) -> Result<()> {
+        self.rakeback = self.rakeback
+            .checked_sub(amount)
+            .ok_or(crate::errors::CasinoError::Overflow)?;
+        Ok(())
+    }
+    
+    pub fn validate_referral_share(share_bp: u64) -> Result<()> {
+        require!(
+            share_bp <= MAX_REFERRAL_SHARE_BP,
+            crate::errors::CasinoError::InvalidReferralShare
+        );
+        Ok(())
+    }
+    
+    /// Instant changes to timelocked parameters are only allowed while the delay is zero
+    pub fn require_no_timelock(&self) -> Result<()> {
+        require!(self.timelock_delay == 0, crate::errors::CasinoError::TimelockRequired);
+        Ok(())
+    }
+    
+    /// Limits accepted by `initialize` and every later change: the vault must
+    /// hold at least twice the max bet
+    pub fn validate_bet_limits(min_bet: u64, max_bet: u64, vault_balance: u64) -> Result<()> {
+        require!(min_bet > 0, crate::errors::CasinoError::InvalidBetAmount);
+        require!(max_bet >= min_bet, crate::errors::CasinoError::InvalidBetAmount);
+        require!(
+            max_bet <= vault_balance / 2,
+            crate::errors::CasinoError::InvalidBetAmount
+        );
+        Ok(())
+    }
+    
+    /// Count a Treasury withdrawal against the current epoch's cap
+    pub fn record_treasury_withdrawal(&mut self, amount: u64, epoch: u64) -> Result<()> {
+        if self.treasury_epoch != epoch {
+            self.treasury_epoch = epoch;
+            self.treasury_withdrawn_in_epoch = 0;
+        }
+        let withdrawn = self.treasury_withdrawn_in_epoch
+            .checked_add(amount)
+            .ok_or(crate::errors::CasinoError::Overflow)?;
+        require!(
+            withdrawn <= self.treasury_epoch_cap,
+            crate::errors::CasinoError::TreasuryEpochCapExceeded
+        );
+        self.treasury_withdrawn_in_epoch = withdrawn;
+        self.total_treasury_withdrawn = self.total_treasury_withdrawn
+            .checked_add(amount)
+            .ok_or(crate::errors::CasinoError::Overflow)?;
+        Ok(())
+    }
+    
     pub fn validate_bet_amount(&self, amount: u64) -> Result<()> {
         require!(
             amount >= self.min_bet && amount <= self.max_bet,
@@ -29,3 +311,166 @@ impl CasinoConfig {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::CasinoError;
+
+    const RENT: u64 = 890_880;
+
+    fn casino(reserve_policy: ReservePolicy) -> CasinoConfig {
+        CasinoConfig {
+            authority: Pubkey::default(),
+            pending_authority: None,
+            vault_bump: 0,
+            treasury_bump: 0,
+            min_bet: 10,
+            max_bet: 400,
+            total_games: 0,
+            total_volume: 0,
+            total_payouts: 0,
+            total_treasury_skimmed: 0,
+            is_active: true,
+            switchboard_function: None,
+            oracle: Pubkey::default(),
+            randomness_source: RandomnessSource::SlotHash,
+            pending_liability: 0,
+            unclaimed_payouts: 0,
+            player_balances: 0,
+            timelock_delay: 0,
+            next_change_id: 0,
+            treasury_epoch_cap: 0,
+            treasury_epoch: 0,
+            treasury_withdrawn_in_epoch: 0,
+            total_treasury_withdrawn: 0,
+            reserve_policy,
+            lp_mint: None,
+            referral_share_bp: 0,
+            referral_rewards: 0,
+            rakeback: 0,
+            auto_close_sessions: false,
+        }
+    }
+
+    /// Liabilities of 1600 lamports spread over every kind
+    fn casino_with_liabilities(reserve_policy: ReservePolicy) -> CasinoConfig {
+        CasinoConfig {
+            pending_liability: 1_000,
+            unclaimed_payouts: 200,
+            player_balances: 300,
+            referral_rewards: 50,
+            rakeback: 50,
+            ..casino(reserve_policy)
+        }
+    }
+
+    fn policy(floor: u64, max_bet_multiple: u64, liability_bp: u64) -> ReservePolicy {
+        ReservePolicy { floor, max_bet_multiple, liability_bp, crank_tip_bp: 0 }
+    }
+
+    #[test]
+    fn required_balance_uses_the_largest_reserve_component() {
+        // Floor 500, 2 x max bet = 800, 100% of liabilities = 1600
+        let casino = casino_with_liabilities(policy(500, 2, 10_000));
+        assert_eq!(casino.required_vault_balance(RENT).unwrap(), 1_600 + 1_600 + RENT);
+
+        let casino = casino_with_liabilities(policy(500, 2, 2_500));
+        assert_eq!(casino.required_vault_balance(RENT).unwrap(), 1_600 + 800 + RENT);
+
+        let casino = casino_with_liabilities(policy(5_000, 2, 2_500));
+        assert_eq!(casino.required_vault_balance(RENT).unwrap(), 1_600 + 5_000 + RENT);
+    }
+
+    #[test]
+    fn required_balance_without_a_reserve_is_liabilities_and_rent() {
+        let casino = casino_with_liabilities(policy(0, 0, 0));
+        assert_eq!(casino.required_vault_balance(RENT).unwrap(), 1_600 + RENT);
+        assert_eq!(casino.required_vault_balance(0).unwrap(), 1_600);
+    }
+
+    #[test]
+    fn required_balance_overflow_is_an_error() {
+        let casino = CasinoConfig { pending_liability: u64::MAX, ..casino(policy(1, 0, 0)) };
+        assert_eq!(casino.required_vault_balance(0).unwrap_err(), CasinoError::Overflow.into());
+    }
+
+    #[test]
+    fn reserving_keeps_the_vault_rent_exempt() {
+        let mut casino = casino_with_liabilities(policy(0, 0, 0));
+        // One lamport short once the rent-exempt minimum is left alone
+        assert_eq!(
+            casino.reserve_liability(500, 1_600 + 500 + RENT - 1, RENT).unwrap_err(),
+            CasinoError::InsufficientVaultLiquidity.into()
+        );
+        assert_eq!(casino.pending_liability, 1_000);
+
+        casino.reserve_liability(500, 1_600 + 500 + RENT, RENT).unwrap();
+        assert_eq!(casino.pending_liability, 1_500);
+        assert_eq!(casino.total_liabilities().unwrap(), 2_100);
+    }
+
+    #[test]
+    fn reserving_ignores_the_reserve_policy() {
+        // The policy only limits skims; bets may use the reserve itself
+        let mut casino = casino_with_liabilities(policy(1_000_000, 0, 0));
+        casino.reserve_liability(500, 1_600 + 500 + RENT, RENT).unwrap();
+        casino.release_liability(500).unwrap();
+        assert_eq!(casino.pending_liability, 1_000);
+    }
+
+    #[test]
+    fn treasury_withdrawals_can_reach_the_cap_exactly() {
+        let mut casino = CasinoConfig { treasury_epoch_cap: 1_000, ..casino(ReservePolicy::default()) };
+        casino.record_treasury_withdrawal(600, 10).unwrap();
+        casino.record_treasury_withdrawal(400, 10).unwrap();
+        assert_eq!(
+            casino.record_treasury_withdrawal(1, 10).unwrap_err(),
+            CasinoError::TreasuryEpochCapExceeded.into()
+        );
+        assert_eq!(casino.treasury_withdrawn_in_epoch, 1_000);
+        assert_eq!(casino.total_treasury_withdrawn, 1_000);
+    }
+
+    #[test]
+    fn treasury_cap_resets_at_the_next_epoch() {
+        let mut casino = CasinoConfig { treasury_epoch_cap: 1_000, ..casino(ReservePolicy::default()) };
+        casino.record_treasury_withdrawal(1_000, 10).unwrap();
+
+        casino.record_treasury_withdrawal(1_000, 11).unwrap();
+        assert_eq!(casino.treasury_epoch, 11);
+        assert_eq!(casino.treasury_withdrawn_in_epoch, 1_000);
+        assert_eq!(casino.total_treasury_withdrawn, 2_000);
+
+        // The cap still applies to a single withdrawal in a fresh epoch
+        assert_eq!(
+            casino.record_treasury_withdrawal(1_001, 12).unwrap_err(),
+            CasinoError::TreasuryEpochCapExceeded.into()
+        );
+        casino.record_treasury_withdrawal(1_000, 12).unwrap();
+    }
+
+    #[test]
+    fn zero_treasury_cap_blocks_withdrawals() {
+        let mut casino = casino(ReservePolicy::default());
+        assert_eq!(
+            casino.record_treasury_withdrawal(1, 0).unwrap_err(),
+            CasinoError::TreasuryEpochCapExceeded.into()
+        );
+    }
+
+    #[test]
+    fn reserve_policy_loosening() {
+        let current = ReservePolicy::default();
+        assert!(!current.loosens(&current));
+        assert!(!ReservePolicy { floor: current.floor + 1, ..current }.loosens(&current));
+        assert!(!ReservePolicy { crank_tip_bp: 0, ..current }.loosens(&current));
+
+        assert!(ReservePolicy { floor: current.floor - 1, ..current }.loosens(&current));
+        assert!(ReservePolicy { max_bet_multiple: 1, ..current }.loosens(&current));
+        assert!(ReservePolicy { crank_tip_bp: current.crank_tip_bp + 1, ..current }.loosens(&current));
+        // A tighter component does not make up for a looser one
+        let mixed = ReservePolicy { floor: 0, liability_bp: 5_000, ..current };
+        assert!(mixed.loosens(&current));
+    }
+}
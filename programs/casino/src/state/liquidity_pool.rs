@@ -0,0 +1,292 @@
+use anchor_lang::prelude::*;
+use crate::errors::CasinoError;
+use super::{LP_DEPOSIT_COOLDOWN_SECONDS, LP_WITHDRAWAL_COOLDOWN_SECONDS};
+
+/// LP share tokens use the same precision as SOL; the first shares are minted 1:1 with lamports
+pub const LP_MINT_DECIMALS: u8 = 9;
+
+/// House bankroll pool. LP shares are a claim on the vault's net asset value
+/// (vault balance minus liabilities and the vault's rent-exempt minimum), so
+/// house profit and loss are shared pro-rata by all holders.
+#[account]
+#[derive(InitSpace)]
+pub struct LiquidityPool {
+    /// Share token mint, authority is this PDA
+    pub lp_mint: Pubkey,
+    
+    /// Lifetime lamports deposited by LPs
+    pub total_deposited: u64,
+    
+    /// Lifetime lamports redeemed by LPs
+    pub total_withdrawn: u64,
+    
+    /// PDA bump
+    pub bump: u8,
+    
+    /// LP mint PDA bump
+    pub mint_bump: u8,
+    
+    /// Escrow token account PDA bump (holds shares of queued withdrawals)
+    pub escrow_bump: u8,
+    
+    /// ID of the next `WithdrawalRequest`
+    pub next_request_id: u64,
+    
+    /// ID of the oldest unprocessed `WithdrawalRequest`; requests are processed in order
+    pub queue_head: u64,
+    
+    /// Shares locked in escrow by queued requests
+    pub queued_shares: u64,
+    
+    /// Epoch the NAV snapshot below was taken in
+    pub snapshot_epoch: u64,
+    
+    /// NAV and share supply at the first deposit or withdrawal processed in
+    /// `snapshot_epoch`. Everything processed in that epoch is priced with them.
+    pub snapshot_nav: u64,
+    pub snapshot_supply: u64,
+    
+    /// When the snapshot was taken; deposits requested later wait for the next one
+    pub snapshot_taken_at: i64,
+    
+    /// Lamports held by queued `DepositRequest`s (not yet in the vault)
+    pub queued_deposits: u64,
+}
+
+impl LiquidityPool {
+    /// Shares minted for a deposit of `amount` lamports
+    pub fn shares_for_deposit(amount: u64, nav: u64, supply: u64) -> Result<u64> {
+        if supply == 0 {
+            return Ok(amount);
+        }
+        // Shares outstanding but nothing backing them: new deposits would be
+        // handed to the existing holders
+        require!(nav > 0, CasinoError::InvalidLiquidityAmount);
+        Self::mul_div(amount, supply, nav)
+    }
+    
+    /// Take the epoch's NAV snapshot unless one was already taken this epoch
+    pub fn snapshot_nav(&mut self, epoch: u64, nav: u64, supply: u64, now: i64) {
+        if self.snapshot_epoch != epoch || self.snapshot_supply == 0 {
+            self.snapshot_epoch = epoch;
+            self.snapshot_nav = nav;
+            self.snapshot_supply = supply;
+            self.snapshot_taken_at = now;
+        }
+    }
+    
+    /// Shares for a queued deposit at the snapshot price. The snapshot must be
+    /// newer than the request, so the depositor could not know the price.
+    pub fn shares_for_queued_deposit(&self, request: &DepositRequest) -> Result<u64> {
+        require!(
+            self.snapshot_taken_at > request.requested_at,
+            CasinoError::DepositCooldownActive
+        );
+        Self::shares_for_deposit(request.amount, self.snapshot_nav, self.snapshot_supply)
+    }
+    
+    /// Fill as much of a request for `shares` as `available` lamports allow,
+    /// at the snapshot price. Returns `(shares_filled, lamports)`.
+    pub fn fill(&self, shares: u64, available: u64) -> Result<(u64, u64)> {
+        require!(self.snapshot_supply > 0, CasinoError::InvalidLiquidityAmount);
+        let owed = Self::mul_div(shares, self.snapshot_nav, self.snapshot_supply)?;
+        if owed <= available {
+            return Ok((shares, owed));
+        }
+        if self.snapshot_nav == 0 {
+            return Ok((0, 0));
+        }
+        let shares_filled = Self::mul_div(available, self.snapshot_supply, self.snapshot_nav)?;
+        let lamports = Self::mul_div(shares_filled, self.snapshot_nav, self.snapshot_supply)?;
+        Ok((shares_filled, lamports))
+    }
+    
+    /// `a * b / c`, rounded down in the pool's favour
+    fn mul_div(a: u64, b: u64, c: u64) -> Result<u64> {
+        let result = (a as u128)
+            .checked_mul(b as u128)
+            .ok_or(CasinoError::Overflow)?
+            / c as u128;
+        u64::try_from(result).map_err(|_| error!(CasinoError::Overflow))
+    }
+}
+
+/// A queued LP withdrawal at `[WITHDRAWAL_REQUEST_SEED, casino, id (LE)]`.
+/// Its shares sit in the pool escrow until processed or cancelled.
+#[account]
+#[derive(InitSpace)]
+pub struct WithdrawalRequest {
+    pub id: u64,
+    
+    pub owner: Pubkey,
+    
+    /// Shares still waiting to be redeemed (0 once cancelled)
+    pub shares_remaining: u64,
+    
+    pub shares_requested: u64,
+    
+    /// Lamports paid so far (partial fills)
+    pub lamports_paid: u64,
+    
+    pub requested_at: i64,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+/// A queued LP deposit at `[DEPOSIT_REQUEST_SEED, casino, owner]`. Holds the
+/// deposited lamports until `process_deposit` moves them into the vault.
+#[account]
+#[derive(InitSpace)]
+pub struct DepositRequest {
+    pub owner: Pubkey,
+    
+    /// Token account receiving the shares
+    pub owner_shares: Pubkey,
+    
+    /// Lamports held by this account on top of its rent
+    pub amount: u64,
+    
+    /// Fewer shares than this refunds the deposit instead
+    pub min_shares: u64,
+    
+    pub requested_at: i64,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl DepositRequest {
+    pub fn cooldown_elapsed(&self, now: i64) -> bool {
+        now >= self.requested_at.saturating_add(LP_DEPOSIT_COOLDOWN_SECONDS)
+    }
+}
+
+impl WithdrawalRequest {
+    pub fn cooldown_elapsed(&self, now: i64) -> bool {
+        now >= self.requested_at.saturating_add(LP_WITHDRAWAL_COOLDOWN_SECONDS)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EPOCH: u64 = 500;
+    const T0: i64 = 1_700_000_000;
+
+    fn pool(snapshot_nav: u64, snapshot_supply: u64) -> LiquidityPool {
+        LiquidityPool {
+            lp_mint: Pubkey::default(),
+            total_deposited: 0,
+            total_withdrawn: 0,
+            bump: 0,
+            mint_bump: 0,
+            escrow_bump: 0,
+            next_request_id: 0,
+            queue_head: 0,
+            queued_shares: 0,
+            snapshot_epoch: EPOCH,
+            snapshot_nav,
+            snapshot_supply,
+            snapshot_taken_at: T0,
+            queued_deposits: 0,
+        }
+    }
+
+    #[test]
+    fn first_deposit_mints_one_share_per_lamport() {
+        assert_eq!(LiquidityPool::shares_for_deposit(1_000, 0, 0).unwrap(), 1_000);
+        // Profit left in the vault before any LP joined goes to the first depositor
+        assert_eq!(LiquidityPool::shares_for_deposit(1_000, 5_000, 0).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn deposits_are_priced_at_nav_per_share() {
+        // NAV above supply: each share is worth 2 lamports
+        assert_eq!(LiquidityPool::shares_for_deposit(1_000, 2_000, 1_000).unwrap(), 500);
+        // NAV below supply: each share is worth half a lamport
+        assert_eq!(LiquidityPool::shares_for_deposit(1_000, 500, 1_000).unwrap(), 2_000);
+    }
+
+    #[test]
+    fn deposits_round_down_in_the_pools_favour() {
+        // 10 * 1 / 3 = 3.33 shares
+        assert_eq!(LiquidityPool::shares_for_deposit(10, 3, 1).unwrap(), 3);
+        // Less than one share's worth mints nothing
+        assert_eq!(LiquidityPool::shares_for_deposit(1, 3, 1).unwrap(), 0);
+    }
+
+    #[test]
+    fn deposits_into_worthless_shares_are_rejected() {
+        assert_eq!(
+            LiquidityPool::shares_for_deposit(1_000, 0, 1_000).unwrap_err(),
+            CasinoError::InvalidLiquidityAmount.into()
+        );
+    }
+
+    #[test]
+    fn snapshot_is_kept_for_the_rest_of_the_epoch() {
+        let mut pool = pool(1_000, 1_000);
+        pool.snapshot_nav(EPOCH, 5_000, 2_000, T0 + 10);
+        assert_eq!((pool.snapshot_nav, pool.snapshot_supply, pool.snapshot_taken_at), (1_000, 1_000, T0));
+
+        pool.snapshot_nav(EPOCH + 1, 5_000, 2_000, T0 + 10);
+        assert_eq!((pool.snapshot_nav, pool.snapshot_supply, pool.snapshot_taken_at), (5_000, 2_000, T0 + 10));
+    }
+
+    #[test]
+    fn snapshot_with_zero_supply_is_retaken_within_the_epoch() {
+        let mut pool = pool(0, 0);
+        pool.snapshot_nav(EPOCH, 5_000, 2_000, T0 + 10);
+        assert_eq!((pool.snapshot_nav, pool.snapshot_supply), (5_000, 2_000));
+    }
+
+    #[test]
+    fn queued_deposit_needs_a_newer_snapshot() {
+        let pool = pool(2_000, 1_000);
+        let mut request = DepositRequest {
+            owner: Pubkey::default(),
+            owner_shares: Pubkey::default(),
+            amount: 1_000,
+            min_shares: 0,
+            requested_at: T0,
+            bump: 0,
+        };
+        assert_eq!(
+            pool.shares_for_queued_deposit(&request).unwrap_err(),
+            CasinoError::DepositCooldownActive.into()
+        );
+
+        request.requested_at = T0 - 1;
+        assert_eq!(pool.shares_for_queued_deposit(&request).unwrap(), 500);
+    }
+
+    #[test]
+    fn fill_pays_in_full_when_liquidity_covers_it() {
+        assert_eq!(pool(2_000, 1_000).fill(100, 10_000).unwrap(), (100, 200));
+        assert_eq!(pool(2_000, 1_000).fill(100, 200).unwrap(), (100, 200));
+    }
+
+    #[test]
+    fn fill_is_partial_when_liquidity_falls_short() {
+        assert_eq!(pool(2_000, 1_000).fill(100, 50).unwrap(), (25, 50));
+        // 100 lamports buy 33.3 shares at 3 lamports each
+        assert_eq!(pool(3_000, 1_000).fill(100, 100).unwrap(), (33, 99));
+    }
+
+    #[test]
+    fn fill_rounds_down_in_the_pools_favour() {
+        // One share is worth 2/3 of a lamport
+        assert_eq!(pool(2, 3).fill(1, 10).unwrap(), (1, 0));
+        assert_eq!(pool(2, 3).fill(2, 10).unwrap(), (2, 1));
+    }
+
+    #[test]
+    fn fill_with_zero_supply_is_rejected() {
+        assert_eq!(
+            pool(1_000, 0).fill(100, 1_000).unwrap_err(),
+            CasinoError::InvalidLiquidityAmount.into()
+        );
+    }
+}
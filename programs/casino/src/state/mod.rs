@@ -1,17 +1,74 @@
 pub mod casino;
 pub mod session;
+pub mod seed_chain;
+pub mod role;
+pub mod pending_change;
+pub mod treasury_destination;
+pub mod revenue_split;
+pub mod liquidity_pool;
+pub mod token_vault;
+pub mod player_balance;
+pub mod session_key;
+pub mod player;
+pub mod vip_config;
+pub mod player_limits;
 
 pub use casino::*;
 pub use session::*;
+pub use seed_chain::*;
+pub use role::*;
+pub use pending_change::*;
+pub use treasury_destination::*;
+pub use revenue_split::*;
+pub use liquidity_pool::*;
+pub use token_vault::*;
+pub use player_balance::*;
+pub use session_key::*;
+pub use player::*;
+pub use vip_config::*;
+pub use player_limits::*;
 
 // PDA Seeds
 pub const CASINO_SEED: &[u8] = b"casino";
 pub const VAULT_SEED: &[u8] = b"vault";
 pub const TREASURY_SEED: &[u8] = b"treasury";
 pub const SESSION_SEED: &[u8] = b"session";
+pub const SEED_CHAIN_SEED: &[u8] = b"seed_chain";
+pub const ROLE_SEED: &[u8] = b"role";
+pub const PENDING_CHANGE_SEED: &[u8] = b"pending_change";
+pub const TREASURY_DESTINATION_SEED: &[u8] = b"treasury_destination";
+pub const REVENUE_SPLIT_SEED: &[u8] = b"revenue_split";
+pub const LIQUIDITY_POOL_SEED: &[u8] = b"liquidity_pool";
+pub const LP_MINT_SEED: &[u8] = b"lp_mint";
+pub const LP_ESCROW_SEED: &[u8] = b"lp_escrow";
+pub const WITHDRAWAL_REQUEST_SEED: &[u8] = b"withdrawal_request";
+pub const DEPOSIT_REQUEST_SEED: &[u8] = b"deposit_request";
+pub const TOKEN_VAULT_SEED: &[u8] = b"token_vault";
+pub const PLAYER_BALANCE_SEED: &[u8] = b"player_balance";
+pub const SESSION_KEY_SEED: &[u8] = b"session_key";
+pub const PLAYER_SEED: &[u8] = b"player";
+pub const VIP_CONFIG_SEED: &[u8] = b"vip_config";
+pub const PLAYER_LIMITS_SEED: &[u8] = b"player_limits";
 
 // Game constants
 pub const BASIS_POINTS: u64 = 10000;
 pub const WIN_PROBABILITY_BP: u64 = 4800; // 48%
 pub const PAYOUT_MULTIPLIER_BP: u64 = 19600; // 1.96x
+pub const DICE_PAYOUT_MULTIPLIER_BP: u64 = 50000; // 5x
+pub const SLOTS_PAYOUT_MULTIPLIER_BP: u64 = 100000; // 10x
 pub const SESSION_EXPIRY_SECONDS: i64 = 3600; // 1 hour
+pub const MAX_CLIENT_SEED_LEN: usize = 64;
+pub const MAX_TIMELOCK_DELAY_SECONDS: i64 = 30 * 24 * 3600; // 30 days
+pub const LP_DEPOSIT_COOLDOWN_SECONDS: i64 = 24 * 3600; // 1 day
+pub const LP_WITHDRAWAL_COOLDOWN_SECONDS: i64 = 24 * 3600; // 1 day
+pub const MAX_SESSION_KEY_DURATION_SECONDS: i64 = 7 * 24 * 3600; // 7 days
+pub const LIMIT_COOLING_OFF_SECONDS: i64 = 24 * 3600; // 1 day
+
+// Reserve policy defaults
+pub const DEFAULT_RESERVE_FLOOR: u64 = 500_000_000; // 0.5 SOL
+pub const DEFAULT_RESERVE_MAX_BET_MULTIPLE: u64 = 2;
+pub const DEFAULT_CRANK_TIP_BP: u64 = 10; // 0.1%
+pub const MAX_CRANK_TIP_BP: u64 = 100; // 1%
+pub const DEFAULT_REFERRAL_SHARE_BP: u64 = 1000; // 10% of the house edge
+pub const MAX_REFERRAL_SHARE_BP: u64 = 5000; // 50% of the house edge
+pub const MAX_RAKEBACK_BP: u64 = 5000; // 50% of the house edge
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use super::{BeneficiaryShare, RandomnessSource, ReservePolicy};
+
+/// Parameter change that has to go through the timelock
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, InitSpace)]
+pub enum ConfigChange {
+    BetLimits { min_bet: u64, max_bet: u64 },
+    
+    /// Executing only nominates the new authority; it still has to `accept_authority`
+    Authority { new_authority: Pubkey },
+    
+    RandomnessSource { source: RandomnessSource },
+    
+    /// Ed25519 key signing oracle randomness for new bets
+    Oracle { oracle: Pubkey },
+    
+    /// `None` clears the Switchboard function
+    SwitchboardFunction { function: Option<Pubkey> },
+    
+    TimelockDelay { delay: i64 },
+    
+    /// Replaces an existing `RevenueSplit` table
+    RevenueSplit {
+        #[max_len(8)]
+        shares: Vec<BeneficiaryShare>,
+    },
+    
+    TreasuryEpochCap { cap: u64 },
+    
+    ReservePolicy { policy: ReservePolicy },
+    
+    ReferralShare { share_bp: u64 },
+}
+
+/// A queued `ConfigChange`, executable once `eta` has passed
+#[account]
+#[derive(InitSpace)]
+pub struct PendingChange {
+    /// Sequential ID (from `casino.next_change_id` at queue time)
+    pub id: u64,
+    
+    pub change: ConfigChange,
+    
+    /// Authority that queued the change, refunded the rent when it is closed
+    pub proposer: Pubkey,
+    
+    pub queued_at: i64,
+    
+    /// Earliest execution time
+    pub eta: i64,
+    
+    /// PDA bump
+    pub bump: u8,
+}
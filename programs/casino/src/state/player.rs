@@ -1,7 +1,25 @@
 use anchor_lang::prelude::*;
-use super::PlayerStats;
+use crate::errors::CasinoError;
+use super::{GameType, VipConfig};
 
+/// Lifetime game statistics, in lamports for amounts
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct PlayerStats {
+    pub games_played: u64,
+    pub games_won: u64,
+    pub total_wagered: u64,
+    pub total_won: u64,
+    pub biggest_win: u64,
+    /// Positive for consecutive wins, negative for consecutive losses
+    pub current_streak: i32,
+    /// Longest win or loss streak
+    pub best_streak: u32,
+}
+
+/// Player profile at `[PLAYER_SEED, casino, authority]`, updated by
+/// settlement when passed as `player_profile`
 #[account]
+#[derive(InitSpace)]
 pub struct Player {
     /// Player's wallet address
     pub authority: Pubkey,
@@ -13,12 +31,18 @@ pub struct Player {
     pub total_deposited: u64,
     /// Total withdrawal amount
     pub total_withdrawn: u64,
-    /// Is VIP player
-    pub is_vip: bool,
+    /// VIP tier reached (0 = none), see `VipConfig`; never goes down
+    pub vip_tier: u8,
+    /// VIP rakeback accrued and not yet claimed
+    pub rakeback: u64,
+    /// Lifetime rakeback claimed
+    pub rakeback_claimed: u64,
     /// Referrer (if any)
     pub referrer: Option<Pubkey>,
-    /// Referral rewards earned
+    /// Referral rewards accrued and not yet claimed
     pub referral_rewards: u64,
+    /// Lifetime referral rewards claimed
+    pub referral_rewards_claimed: u64,
     /// Last game timestamp
     pub last_game_at: Option<i64>,
     /// Created timestamp
@@ -28,43 +52,159 @@ pub struct Player {
 }
 
 impl Player {
-    pub const LEN: usize = 8 + // discriminator
-        32 + // authority
-        (8 * 8 + 4 + 4) + // stats
-        8 + // treasury_contribution
-        8 + // total_deposited
-        8 + // total_withdrawn
-        1 + // is_vip
-        1 + 32 + // referrer (optional)
-        8 + // referral_rewards
-        1 + 8 + // last_game_at (optional)
-        8 + // created_at
-        1 + // bump
-        64; // padding
-
-    pub fn update_after_game(&mut self, bet_amount: u64, payout: u64, won: bool) {
-        self.stats.games_played += 1;
-        self.stats.total_wagered += bet_amount;
-        self.stats.total_won += payout;
-
+    /// Move up to the tier the lifetime wager now reaches and accrue the
+    /// tier's rakeback on `bet_amount`
+    pub fn apply_vip(&mut self, vip_config: &VipConfig, game_type: GameType, choice: u8, bet_amount: u64) -> Result<u64> {
+        self.vip_tier = self.vip_tier.max(vip_config.tier_for(self.stats.total_wagered));
+        let rakeback = game_type.house_edge_share(choice, bet_amount, vip_config.rakeback_bp(self.vip_tier))?;
+        self.rakeback = self.rakeback
+            .checked_add(rakeback)
+            .ok_or(CasinoError::Overflow)?;
+        Ok(rakeback)
+    }
+    
+    pub fn accrue_referral_reward(&mut self, amount: u64) -> Result<()> {
+        self.referral_rewards = self.referral_rewards
+            .checked_add(amount)
+            .ok_or(CasinoError::Overflow)?;
+        Ok(())
+    }
+    
+    pub fn update_after_game(&mut self, bet_amount: u64, payout: u64, won: bool, now: i64) -> Result<()> {
+        let stats = &mut self.stats;
+        stats.games_played = stats.games_played
+            .checked_add(1)
+            .ok_or(CasinoError::Overflow)?;
+        stats.total_wagered = stats.total_wagered
+            .checked_add(bet_amount)
+            .ok_or(CasinoError::Overflow)?;
+        stats.total_won = stats.total_won
+            .checked_add(payout)
+            .ok_or(CasinoError::Overflow)?;
+        
         if won {
-            self.stats.biggest_win = self.stats.biggest_win.max(payout);
-            self.stats.current_streak = if self.stats.current_streak >= 0 {
-                self.stats.current_streak + 1
+            stats.games_won = stats.games_won
+                .checked_add(1)
+                .ok_or(CasinoError::Overflow)?;
+            stats.biggest_win = stats.biggest_win.max(payout);
+            stats.current_streak = if stats.current_streak >= 0 {
+                stats.current_streak
+                    .checked_add(1)
+                    .ok_or(CasinoError::Overflow)?
             } else {
                 1
             };
         } else {
             // Track Treasury contribution (losses)
-            self.treasury_contribution += bet_amount;
-            self.stats.current_streak = if self.stats.current_streak <= 0 {
-                self.stats.current_streak - 1
+            self.treasury_contribution = self.treasury_contribution
+                .checked_add(bet_amount)
+                .ok_or(CasinoError::Overflow)?;
+            stats.current_streak = if stats.current_streak <= 0 {
+                stats.current_streak
+                    .checked_sub(1)
+                    .ok_or(CasinoError::Overflow)?
             } else {
                 -1
             };
         }
+        
+        stats.best_streak = stats.best_streak.max(stats.current_streak.unsigned_abs());
+        self.last_game_at = Some(now);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{VipTier, BASIS_POINTS, MAX_RAKEBACK_BP, MAX_REFERRAL_SHARE_BP};
+
+    const BET: u64 = 1_000_000;
+
+    fn player(total_wagered: u64) -> Player {
+        Player {
+            authority: Pubkey::default(),
+            stats: PlayerStats { total_wagered, ..PlayerStats::default() },
+            treasury_contribution: 0,
+            total_deposited: 0,
+            total_withdrawn: 0,
+            vip_tier: 0,
+            rakeback: 0,
+            rakeback_claimed: 0,
+            referrer: None,
+            referral_rewards: 0,
+            referral_rewards_claimed: 0,
+            last_game_at: None,
+            created_at: 0,
+            bump: 0,
+        }
+    }
+
+    fn vip_config(tiers: Vec<VipTier>) -> VipConfig {
+        VipConfig { tiers, bump: 0 }
+    }
+
+    #[test]
+    fn vip_tier_is_reached_at_its_threshold() {
+        let config = vip_config(vec![VipTier { min_wagered: 1_000, rakeback_bp: 5_000 }]);
+
+        let mut below = player(999);
+        assert_eq!(below.apply_vip(&config, GameType::CoinFlip, 0, BET).unwrap(), 0);
+        assert_eq!(below.vip_tier, 0);
+
+        // Coin flip edge is 2%, half of it paid back
+        let mut reached = player(1_000);
+        assert_eq!(reached.apply_vip(&config, GameType::CoinFlip, 0, BET).unwrap(), 10_000);
+        assert_eq!(reached.vip_tier, 1);
+        reached.apply_vip(&config, GameType::CoinFlip, 0, BET).unwrap();
+        assert_eq!(reached.rakeback, 20_000);
+    }
+
+    #[test]
+    fn vip_tier_never_goes_down() {
+        let mut player = player(1_000);
+        player.vip_tier = 2;
+        let config = vip_config(vec![
+            VipTier { min_wagered: 1_000, rakeback_bp: 1_000 },
+            VipTier { min_wagered: 1_000_000, rakeback_bp: 2_000 },
+        ]);
+        player.apply_vip(&config, GameType::CoinFlip, 0, BET).unwrap();
+        assert_eq!(player.vip_tier, 2);
+
+        // Once the table loses the tier it pays no rakeback, but the tier is kept
+        assert_eq!(player.apply_vip(&vip_config(Vec::new()), GameType::CoinFlip, 0, BET).unwrap(), 0);
+        assert_eq!(player.vip_tier, 2);
+    }
+
+    #[test]
+    fn rakeback_and_referral_share_stay_within_the_house_edge() {
+        let bets = [(GameType::CoinFlip, 0), (GameType::Slots, 0)]
+            .into_iter()
+            .chain((2..=12).map(|choice| (GameType::Dice, choice)));
+        for (game_type, choice) in bets {
+            // Odd amount so both shares round down
+            let bet = 1_000_003;
+            let edge = game_type.house_edge_share(choice, bet, BASIS_POINTS).unwrap();
+            let rakeback = game_type.house_edge_share(choice, bet, MAX_RAKEBACK_BP).unwrap();
+            let referral = game_type.house_edge_share(choice, bet, MAX_REFERRAL_SHARE_BP).unwrap();
+            assert!(rakeback + referral <= edge, "{rakeback} + {referral} > {edge}");
+            assert!(edge - (rakeback + referral) <= 1);
+        }
+    }
+
+    #[test]
+    fn streaks_and_losses_are_tracked() {
+        let mut player = player(0);
+        player.update_after_game(100, 196, true, 1).unwrap();
+        player.update_after_game(100, 196, true, 2).unwrap();
+        player.update_after_game(100, 0, false, 3).unwrap();
 
-        self.stats.best_streak = self.stats.best_streak.max(self.stats.current_streak.abs());
-        self.last_game_at = Some(Clock::get().unwrap().unix_timestamp);
+        assert_eq!(player.stats.games_played, 3);
+        assert_eq!(player.stats.games_won, 2);
+        assert_eq!(player.stats.total_wagered, 300);
+        assert_eq!(player.stats.current_streak, -1);
+        assert_eq!(player.stats.best_streak, 2);
+        assert_eq!(player.treasury_contribution, 100);
+        assert_eq!(player.last_game_at, Some(3));
     }
 }
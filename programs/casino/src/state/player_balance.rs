@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::errors::CasinoError;
+
+/// Player funds held in the vault at `[PLAYER_BALANCE_SEED, casino, player]`.
+/// `place_bet_from_balance` debits it and settlement credits wins straight
+/// back, so a player can fund once and play many rounds.
+#[account]
+#[derive(InitSpace)]
+pub struct PlayerBalance {
+    pub player: Pubkey,
+    
+    /// Lamports available to bet or withdraw
+    pub balance: u64,
+    
+    pub total_deposited: u64,
+    pub total_withdrawn: u64,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl PlayerBalance {
+    pub fn credit(&mut self, amount: u64) -> Result<()> {
+        self.balance = self.balance
+            .checked_add(amount)
+            .ok_or(CasinoError::Overflow)?;
+        Ok(())
+    }
+    
+    pub fn debit(&mut self, amount: u64) -> Result<()> {
+        self.balance = self.balance
+            .checked_sub(amount)
+            .ok_or(CasinoError::InsufficientPlayerBalance)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,292 @@
+use anchor_lang::prelude::*;
+use crate::errors::CasinoError;
+
+pub const HOUR_SECONDS: i64 = 3600;
+pub const DAY_SECONDS: i64 = 24 * HOUR_SECONDS;
+/// Hourly buckets backing the rolling 24h window
+pub const HOURLY_BUCKETS: usize = 24;
+/// Daily buckets backing the rolling 7 and 30 day windows
+pub const DAILY_BUCKETS: usize = 30;
+
+/// Self-imposed limits in lamports; `None` means no limit
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct LimitSet {
+    pub daily_wager: Option<u64>,
+    pub weekly_wager: Option<u64>,
+    pub monthly_wager: Option<u64>,
+    pub daily_loss: Option<u64>,
+    pub weekly_loss: Option<u64>,
+    pub monthly_loss: Option<u64>,
+    pub max_bet: Option<u64>,
+}
+
+impl LimitSet {
+    /// Field-wise stricter of the two sets
+    pub fn tightest(&self, other: &LimitSet) -> LimitSet {
+        fn min(a: Option<u64>, b: Option<u64>) -> Option<u64> {
+            match (a, b) {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, None) => a,
+                (None, b) => b,
+            }
+        }
+        LimitSet {
+            daily_wager: min(self.daily_wager, other.daily_wager),
+            weekly_wager: min(self.weekly_wager, other.weekly_wager),
+            monthly_wager: min(self.monthly_wager, other.monthly_wager),
+            daily_loss: min(self.daily_loss, other.daily_loss),
+            weekly_loss: min(self.weekly_loss, other.weekly_loss),
+            monthly_loss: min(self.monthly_loss, other.monthly_loss),
+            max_bet: min(self.max_bet, other.max_bet),
+        }
+    }
+}
+
+/// Wagers and net losses of one hour or day
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, InitSpace)]
+pub struct LimitBucket {
+    /// `timestamp / bucket length` of the period the totals belong to
+    pub period: i64,
+    pub wagered: u64,
+    /// Wagered minus paid out; negative after a net win
+    pub net_loss: i64,
+}
+
+/// Responsible-gaming limits of a player at `[PLAYER_LIMITS_SEED, casino, player]`.
+/// Bet instructions always take this address and enforce the limits once the
+/// account exists. Windows roll at bucket granularity (hours for the daily
+/// window, days for the weekly and monthly ones).
+#[account]
+#[derive(InitSpace)]
+pub struct PlayerLimits {
+    pub player: Pubkey,
+    
+    /// Limits in force
+    pub limits: LimitSet,
+    
+    /// Looser limits waiting for the cooling-off delay
+    pub pending_limits: Option<LimitSet>,
+    pub pending_effective_at: i64,
+    
+    /// No bets before this timestamp
+    pub excluded_until: i64,
+    
+    pub hourly: [LimitBucket; 24],
+    pub daily: [LimitBucket; 30],
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl PlayerLimits {
+    /// Tighten immediately; anything looser waits `cooling_off` seconds
+    pub fn set_limits(&mut self, limits: LimitSet, now: i64, cooling_off: i64) -> Result<()> {
+        self.apply_pending(now);
+        self.limits = self.limits.tightest(&limits);
+        if self.limits == limits {
+            self.pending_limits = None;
+            self.pending_effective_at = 0;
+        } else {
+            self.pending_limits = Some(limits);
+            self.pending_effective_at = now
+                .checked_add(cooling_off)
+                .ok_or(CasinoError::Overflow)?;
+        }
+        Ok(())
+    }
+    
+    /// Promote pending limits whose cooling-off has elapsed
+    pub fn apply_pending(&mut self, now: i64) {
+        if let Some(pending) = self.pending_limits {
+            if now >= self.pending_effective_at {
+                self.limits = pending;
+                self.pending_limits = None;
+                self.pending_effective_at = 0;
+            }
+        }
+    }
+    
+    /// Exclusion can only be extended, never shortened
+    pub fn self_exclude(&mut self, until: i64) {
+        self.excluded_until = self.excluded_until.max(until);
+    }
+    
+    pub fn require_not_excluded(&self, now: i64) -> Result<()> {
+        require!(now >= self.excluded_until, CasinoError::SelfExcluded);
+        Ok(())
+    }
+    
+    /// Check a new bet against every limit (counting it as fully lost) and record it
+    pub fn record_bet(&mut self, bet_amount: u64, now: i64) -> Result<()> {
+        self.apply_pending(now);
+        self.require_not_excluded(now)?;
+        
+        let limits = self.limits;
+        if let Some(max_bet) = limits.max_bet {
+            require!(bet_amount <= max_bet, CasinoError::PlayerLimitExceeded);
+        }
+        
+        let windows = [
+            (self.totals(true, HOURLY_BUCKETS as i64, now), limits.daily_wager, limits.daily_loss),
+            (self.totals(false, 7, now), limits.weekly_wager, limits.weekly_loss),
+            (self.totals(false, DAILY_BUCKETS as i64, now), limits.monthly_wager, limits.monthly_loss),
+        ];
+        for ((wagered, net_loss), wager_limit, loss_limit) in windows {
+            if let Some(limit) = wager_limit {
+                require!(
+                    wagered + bet_amount as u128 <= limit as u128,
+                    CasinoError::PlayerLimitExceeded
+                );
+            }
+            if let Some(limit) = loss_limit {
+                require!(
+                    net_loss + bet_amount as i128 <= limit as i128,
+                    CasinoError::PlayerLimitExceeded
+                );
+            }
+        }
+        
+        let net_loss = i64::try_from(bet_amount).map_err(|_| CasinoError::Overflow)?;
+        self.record(bet_amount, net_loss, now)
+    }
+    
+    /// Count a payout against the net loss of the current windows
+    pub fn record_payout(&mut self, payout: u64, now: i64) -> Result<()> {
+        let net_loss = i64::try_from(payout)
+            .map_err(|_| CasinoError::Overflow)?
+            .checked_neg()
+            .ok_or(CasinoError::Overflow)?;
+        self.record(0, net_loss, now)
+    }
+    
+    fn record(&mut self, wagered: u64, net_loss: i64, now: i64) -> Result<()> {
+        for (buckets, length) in [
+            (&mut self.hourly[..], HOUR_SECONDS),
+            (&mut self.daily[..], DAY_SECONDS),
+        ] {
+            let period = now / length;
+            let bucket = &mut buckets[period.rem_euclid(buckets.len() as i64) as usize];
+            if bucket.period != period {
+                *bucket = LimitBucket { period, wagered: 0, net_loss: 0 };
+            }
+            bucket.wagered = bucket.wagered
+                .checked_add(wagered)
+                .ok_or(CasinoError::Overflow)?;
+            bucket.net_loss = bucket.net_loss
+                .checked_add(net_loss)
+                .ok_or(CasinoError::Overflow)?;
+        }
+        Ok(())
+    }
+    
+    /// Wagered and net loss over the last `periods` hourly or daily buckets
+    fn totals(&self, hourly: bool, periods: i64, now: i64) -> (u128, i128) {
+        let (buckets, length) = if hourly {
+            (&self.hourly[..], HOUR_SECONDS)
+        } else {
+            (&self.daily[..], DAY_SECONDS)
+        };
+        let current = now / length;
+        buckets
+            .iter()
+            .filter(|bucket| bucket.period > current - periods && bucket.period <= current)
+            .fold((0, 0), |(wagered, net_loss), bucket| {
+                (wagered + bucket.wagered as u128, net_loss + bucket.net_loss as i128)
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::LIMIT_COOLING_OFF_SECONDS;
+
+    /// Start of a day (and therefore of an hour)
+    const T0: i64 = 19_700 * DAY_SECONDS;
+
+    fn player_limits(limits: LimitSet) -> PlayerLimits {
+        PlayerLimits {
+            player: Pubkey::default(),
+            limits,
+            pending_limits: None,
+            pending_effective_at: 0,
+            excluded_until: 0,
+            hourly: [LimitBucket::default(); HOURLY_BUCKETS],
+            daily: [LimitBucket::default(); DAILY_BUCKETS],
+            bump: 0,
+        }
+    }
+
+    fn assert_rejected(result: Result<()>) {
+        assert_eq!(result.unwrap_err(), CasinoError::PlayerLimitExceeded.into());
+    }
+
+    #[test]
+    fn hourly_buckets_roll_over_after_a_day() {
+        let mut limits = player_limits(LimitSet { daily_wager: Some(100), ..LimitSet::default() });
+        limits.record_bet(60, T0).unwrap();
+        limits.record_bet(40, T0 + 23 * HOUR_SECONDS).unwrap();
+        assert_rejected(limits.record_bet(1, T0 + 23 * HOUR_SECONDS));
+
+        // 24h later the first hour's bucket is reused for the new period
+        limits.record_bet(60, T0 + DAY_SECONDS).unwrap();
+        let bucket = limits.hourly[(T0 / HOUR_SECONDS) as usize % HOURLY_BUCKETS];
+        assert_eq!(bucket.period, (T0 + DAY_SECONDS) / HOUR_SECONDS);
+        assert_eq!(bucket.wagered, 60);
+        assert_rejected(limits.record_bet(1, T0 + DAY_SECONDS));
+    }
+
+    #[test]
+    fn weekly_window_spans_seven_days() {
+        let mut limits = player_limits(LimitSet { weekly_wager: Some(100), ..LimitSet::default() });
+        limits.record_bet(100, T0).unwrap();
+        assert_rejected(limits.record_bet(1, T0 + 6 * DAY_SECONDS));
+        limits.record_bet(100, T0 + 7 * DAY_SECONDS).unwrap();
+    }
+
+    #[test]
+    fn monthly_window_spans_thirty_days() {
+        let mut limits = player_limits(LimitSet { monthly_loss: Some(100), ..LimitSet::default() });
+        limits.record_bet(100, T0).unwrap();
+        assert_rejected(limits.record_bet(1, T0 + 29 * DAY_SECONDS));
+        limits.record_bet(100, T0 + 30 * DAY_SECONDS).unwrap();
+    }
+
+    #[test]
+    fn payouts_reduce_net_loss() {
+        let mut limits = player_limits(LimitSet { daily_loss: Some(100), ..LimitSet::default() });
+        limits.record_bet(100, T0).unwrap();
+        assert_rejected(limits.record_bet(1, T0));
+        limits.record_payout(40, T0 + HOUR_SECONDS).unwrap();
+        limits.record_bet(40, T0 + HOUR_SECONDS).unwrap();
+        assert_rejected(limits.record_bet(1, T0 + HOUR_SECONDS));
+    }
+
+    #[test]
+    fn tightening_cancels_pending_loosening() {
+        let mut limits = player_limits(LimitSet { max_bet: Some(100), ..LimitSet::default() });
+        let looser = LimitSet { max_bet: Some(200), ..LimitSet::default() };
+        limits.set_limits(looser, T0, LIMIT_COOLING_OFF_SECONDS).unwrap();
+        assert_eq!(limits.limits.max_bet, Some(100));
+        assert!(limits.pending_limits.is_some());
+
+        let tighter = LimitSet { max_bet: Some(50), ..LimitSet::default() };
+        limits.set_limits(tighter, T0 + 1, LIMIT_COOLING_OFF_SECONDS).unwrap();
+        assert_eq!(limits.limits.max_bet, Some(50));
+        assert!(limits.pending_limits.is_none());
+
+        assert_rejected(limits.record_bet(51, T0 + LIMIT_COOLING_OFF_SECONDS + 1));
+    }
+
+    #[test]
+    fn loosening_applies_after_cooling_off() {
+        let mut limits = player_limits(LimitSet { max_bet: Some(100), ..LimitSet::default() });
+        limits.set_limits(LimitSet::default(), T0, LIMIT_COOLING_OFF_SECONDS).unwrap();
+        assert_eq!(limits.limits.max_bet, Some(100));
+
+        assert_rejected(limits.record_bet(200, T0 + LIMIT_COOLING_OFF_SECONDS - 1));
+        limits.record_bet(200, T0 + LIMIT_COOLING_OFF_SECONDS).unwrap();
+        assert_eq!(limits.limits, LimitSet::default());
+        assert!(limits.pending_limits.is_none());
+    }
+}
@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use super::BASIS_POINTS;
+
+/// Upper bound on `RevenueSplit::beneficiaries` (keep in sync with `max_len`)
+pub const MAX_BENEFICIARIES: usize = 8;
+
+/// One destination's share of treasury distributions
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct BeneficiaryShare {
+    pub destination: Pubkey,
+    /// Share of each distribution, in basis points
+    pub share_bp: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct Beneficiary {
+    pub destination: Pubkey,
+    pub share_bp: u16,
+    /// Lifetime lamports received through `distribute_treasury`
+    pub total_received: u64,
+}
+
+/// Split table used by `distribute_treasury`. Shares always sum to `BASIS_POINTS`
+/// and every destination must be an allowlisted `TreasuryDestination` when paid.
+#[account]
+#[derive(InitSpace)]
+pub struct RevenueSplit {
+    #[max_len(8)]
+    pub beneficiaries: Vec<Beneficiary>,
+    
+    /// Lifetime lamports distributed
+    pub total_distributed: u64,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl RevenueSplit {
+    /// 1-`MAX_BENEFICIARIES` distinct, non-zero shares summing to `BASIS_POINTS`
+    pub fn validate_shares(shares: &[BeneficiaryShare]) -> Result<()> {
+        require!(
+            !shares.is_empty() && shares.len() <= MAX_BENEFICIARIES,
+            crate::errors::CasinoError::InvalidRevenueSplit
+        );
+        
+        let mut total_bp: u64 = 0;
+        for (i, share) in shares.iter().enumerate() {
+            require!(share.share_bp > 0, crate::errors::CasinoError::InvalidRevenueSplit);
+            require!(
+                shares[..i].iter().all(|other| other.destination != share.destination),
+                crate::errors::CasinoError::InvalidRevenueSplit
+            );
+            total_bp += share.share_bp as u64;
+        }
+        require!(total_bp == BASIS_POINTS, crate::errors::CasinoError::InvalidRevenueSplit);
+        Ok(())
+    }
+    
+    /// Replace the table. Beneficiaries that stay keep their cumulative
+    /// `total_received`.
+    pub fn set_shares(&mut self, shares: &[BeneficiaryShare]) {
+        self.beneficiaries = shares
+            .iter()
+            .map(|share| Beneficiary {
+                destination: share.destination,
+                share_bp: share.share_bp,
+                total_received: self.beneficiaries
+                    .iter()
+                    .find(|existing| existing.destination == share.destination)
+                    .map_or(0, |existing| existing.total_received),
+            })
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::CasinoError;
+
+    fn destination(n: u8) -> Pubkey {
+        Pubkey::new_from_array([n; 32])
+    }
+
+    fn share(n: u8, share_bp: u16) -> BeneficiaryShare {
+        BeneficiaryShare { destination: destination(n), share_bp }
+    }
+
+    fn assert_invalid(shares: &[BeneficiaryShare]) {
+        assert_eq!(
+            RevenueSplit::validate_shares(shares).unwrap_err(),
+            CasinoError::InvalidRevenueSplit.into()
+        );
+    }
+
+    #[test]
+    fn shares_must_sum_to_ten_thousand() {
+        RevenueSplit::validate_shares(&[share(1, 10_000)]).unwrap();
+        RevenueSplit::validate_shares(&[share(1, 2_500), share(2, 7_500)]).unwrap();
+        assert_invalid(&[share(1, 2_500), share(2, 7_499)]);
+        assert_invalid(&[share(1, 2_500), share(2, 7_501)]);
+    }
+
+    #[test]
+    fn duplicate_destinations_are_rejected() {
+        assert_invalid(&[share(1, 5_000), share(1, 5_000)]);
+        assert_invalid(&[share(1, 4_000), share(2, 2_000), share(1, 4_000)]);
+    }
+
+    #[test]
+    fn table_size_and_zero_shares_are_rejected() {
+        assert_invalid(&[]);
+        assert_invalid(&[share(1, 10_000), share(2, 0)]);
+
+        let eight: Vec<_> = (1..=8).map(|n| share(n, 1_250)).collect();
+        RevenueSplit::validate_shares(&eight).unwrap();
+        let mut nine: Vec<_> = (1..=8).map(|n| share(n, 1_000)).collect();
+        nine.push(share(9, 2_000));
+        assert_invalid(&nine);
+    }
+
+    #[test]
+    fn set_shares_keeps_totals_of_remaining_beneficiaries() {
+        let mut split = RevenueSplit { beneficiaries: Vec::new(), total_distributed: 0, bump: 0 };
+        split.set_shares(&[share(1, 5_000), share(2, 5_000)]);
+        split.beneficiaries[0].total_received = 700;
+        split.beneficiaries[1].total_received = 300;
+
+        split.set_shares(&[share(3, 4_000), share(1, 6_000)]);
+        let totals: Vec<_> = split.beneficiaries
+            .iter()
+            .map(|b| (b.destination, b.share_bp, b.total_received))
+            .collect();
+        assert_eq!(totals, vec![(destination(3), 4_000, 0), (destination(1), 6_000, 700)]);
+    }
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+
+/// Operational roles. Role management and config changes stay with
+/// `CasinoConfig.authority` (the admin), so hot keys can hold a single role
+/// without being able to do anything else.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum Role {
+    /// May settle sessions in `fulfill_randomness`
+    Settler,
+    
+    /// May move funds out of the vault and treasury (`skim_excess_to_treasury`,
+    /// `withdraw_treasury`, `distribute_treasury`, `withdraw_token_vault`)
+    Treasurer,
+    
+    /// May pause and unpause the casino (`set_active`)
+    Pauser,
+}
+
+impl Role {
+    pub fn seed(&self) -> [u8; 1] {
+        [*self as u8]
+    }
+}
+
+/// Grants `role` to `member`. The account existing at
+/// `[ROLE_SEED, casino, role, member]` is the grant; revoking closes it.
+#[account]
+#[derive(InitSpace)]
+pub struct RoleAssignment {
+    pub role: Role,
+    pub member: Pubkey,
+    pub granted_at: i64,
+    pub bump: u8,
+}
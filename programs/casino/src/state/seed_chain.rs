@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+/// Rotating server-seed hash chain for the commit-reveal game mode.
+///
+/// The house generates seeds `s_N .. s_1` with `s_{i-1} = hex(sha256(s_i))`
+/// and publishes `sha256(s_1)` as the first commitment. Bets bind to the
+/// current commitment; revealing `s_i` moves the commitment to `sha256(s_{i+1})`,
+/// which is `s_i` decoded from hex, so the house never picks a commitment after
+/// the first one and anyone can check the revealed seeds link up.
+#[account]
+#[derive(InitSpace)]
+pub struct SeedChain {
+    /// SHA256 of the next server seed to be revealed
+    pub commitment: [u8; 32],
+    
+    /// Incremented every time the house starts a new chain
+    pub chain_id: u64,
+    
+    /// Number of seeds revealed from the current chain
+    pub revealed_count: u64,
+    
+    /// PDA bump
+    pub bump: u8,
+}
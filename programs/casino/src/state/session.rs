@@ -12,9 +12,15 @@ pub struct GameSession {
     /// Game type
     pub game_type: GameType,
     
-    /// Bet amount in lamports
+    /// Bet amount in lamports, or in base units of `mint`
     pub bet_amount: u64,
     
+    /// Token mint of the bet (`None` for SOL bets held in the vault)
+    pub mint: Option<Pubkey>,
+    
+    /// Bet was debited from the player's `PlayerBalance`; wins and refunds are credited back to it
+    pub from_balance: bool,
+    
     /// Player's choice/input (meaning depends on game_type)
     /// CoinFlip: 0 = heads, 1 = tails
     /// Dice: target number (2-12)
@@ -33,6 +39,12 @@ pub struct GameSession {
     /// Creation timestamp
     pub created_at: i64,
     
+    /// Slot the bet was placed in (part of the oracle-signed message)
+    pub created_slot: u64,
+    
+    /// Maximum payout of the bet, reserved in `CasinoConfig.pending_liability` while pending
+    pub max_payout: u64,
+    
     /// Resolution timestamp (if resolved)
     pub resolved_at: Option<i64>,
     
@@ -47,6 +59,59 @@ pub enum GameType {
     Slots,
 }
 
+impl GameType {
+    /// Bit of this game type in `SessionKey::allowed_games`
+    pub fn bit(&self) -> u8 {
+        1 << (*self as u8)
+    }
+    
+    /// Payout multiplier of a winning bet, in basis points
+    pub fn payout_multiplier_bp(&self) -> u64 {
+        match self {
+            GameType::CoinFlip => crate::state::PAYOUT_MULTIPLIER_BP,
+            GameType::Dice => crate::state::DICE_PAYOUT_MULTIPLIER_BP,
+            GameType::Slots => crate::state::SLOTS_PAYOUT_MULTIPLIER_BP,
+        }
+    }
+    
+    /// Chance of a win as `(numerator, denominator)`
+    pub fn win_odds(&self, choice: u8) -> (u64, u64) {
+        match self {
+            GameType::CoinFlip => (1, 2),
+            // Ways to roll `choice` with two dice
+            GameType::Dice => (6u64.saturating_sub((choice as i64 - 7).unsigned_abs()), 36),
+            // All three reels equal
+            GameType::Slots => (10, 1000),
+        }
+    }
+    
+    /// Theoretical house edge of a bet, in basis points
+    pub fn house_edge_bp(&self, choice: u8) -> u64 {
+        let (numerator, denominator) = self.win_odds(choice);
+        let expected_return_bp = self.payout_multiplier_bp() * numerator / denominator;
+        crate::state::BASIS_POINTS.saturating_sub(expected_return_bp)
+    }
+    
+    /// `share_bp` of a bet's theoretical house edge, in the bet's units
+    pub fn house_edge_share(&self, choice: u8, bet_amount: u64, share_bp: u64) -> Result<u64> {
+        let share = (bet_amount as u128)
+            .checked_mul(self.house_edge_bp(choice) as u128)
+            .and_then(|amount| amount.checked_mul(share_bp as u128))
+            .ok_or(crate::errors::CasinoError::Overflow)?
+            / (crate::state::BASIS_POINTS as u128 * crate::state::BASIS_POINTS as u128);
+        u64::try_from(share).map_err(|_| error!(crate::errors::CasinoError::Overflow))
+    }
+    
+    /// Largest amount a bet can pay out. Every game currently has a single
+    /// winning multiplier, so the choice does not change it.
+    pub fn max_payout(&self, _choice: u8, bet_amount: u64) -> Result<u64> {
+        bet_amount
+            .checked_mul(self.payout_multiplier_bp())
+            .and_then(|amount| amount.checked_div(crate::state::BASIS_POINTS))
+            .ok_or(error!(crate::errors::CasinoError::Overflow))
+    }
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
 pub enum SessionStatus {
     /// Awaiting randomness fulfillment
@@ -59,17 +124,57 @@ pub enum SessionStatus {
     Expired,
 }
 
+/// Where a session's randomness comes from. Selected per casino by the admin
+/// and copied into each session at bet time, so switching providers only
+/// affects new bets.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum RandomnessSource {
+    /// Caller-supplied value (only accepted by builds with the `mock-vrf` feature)
+    Mock,
+    
+    /// Switchboard On-Demand randomness account committed with the bet
+    Switchboard,
+    
+    /// Ed25519 signature of the registered oracle over the session
+    OracleSigned,
+    
+    /// Revealed server seed from the house seed chain + player client seed
+    CommitReveal,
+    
+    /// Bank hash of the slot the bet landed in
+    SlotHash,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct RandomnessRequest {
-    /// Whether this is a mock request (localnet only)
-    pub is_mock: bool,
+    /// Randomness source the session is settled with
+    pub source: RandomnessSource,
     
     /// Request signature or ID (for tracking)
     #[max_len(64)]
     pub request_id: String,
     
-    /// Switchboard request account (if using real VRF)
+    /// Switchboard randomness account bound to the session (if using Switchboard)
     pub switchboard_request: Option<Pubkey>,
+    
+    /// Switchboard: seed slot of the commitment at bet time.
+    /// OracleSigned: slot the oracle signature was locked in. 0 otherwise.
+    pub commit_slot: u64,
+    
+    /// Oracle key that must sign the session's randomness, fixed at bet time
+    /// (oracle-signed mode only)
+    pub oracle: Option<Pubkey>,
+    
+    /// Hash of the oracle signature, locked in by the first `fulfill_randomness`
+    /// (oracle-signed mode only)
+    pub oracle_signature_hash: Option<[u8; 32]>,
+    
+    /// Seed-chain commitment the bet is bound to (commit-reveal mode only)
+    pub server_seed_hash: Option<[u8; 32]>,
+    
+    /// Player-chosen client seed mixed into the outcome (commit-reveal mode only)
+    #[max_len(64)]
+    pub client_seed: String,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
@@ -90,9 +195,20 @@ pub struct GameResult {
     /// - For losses: always true (nothing to claim)
     /// - For wins: false until player claims
     pub payout_claimed: bool,
+    
+    /// Version of the outcome derivation used (see `crate::outcome`)
+    pub derivation_version: u8,
 }
 
 impl GameSession {
+    /// Nothing is owed on the session any more: resolved with the payout
+    /// claimed (always the case for losses). Refunds close the session in the
+    /// same instruction, so an `Expired` session is never left to close.
+    pub fn is_closable(&self) -> bool {
+        self.status == SessionStatus::Resolved
+            && self.result.as_ref().is_some_and(|result| result.payout_claimed)
+    }
+    
     pub fn is_expired(&self, current_time: i64) -> bool {
         current_time - self.created_at > crate::state::SESSION_EXPIRY_SECONDS
     }
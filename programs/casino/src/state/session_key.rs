@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::errors::CasinoError;
+use super::GameType;
+
+/// Lets `signer`, an ephemeral keypair held by the player's app, place bets
+/// from `player`'s `PlayerBalance` without a wallet prompt. Lives at
+/// `[SESSION_KEY_SEED, casino, player, signer]`; it can never withdraw.
+#[account]
+#[derive(InitSpace)]
+pub struct SessionKey {
+    pub player: Pubkey,
+    
+    pub signer: Pubkey,
+    
+    /// Unix timestamp after which the key is rejected
+    pub expires_at: i64,
+    
+    /// Total lamports the key may wager over its lifetime
+    pub max_total_wager: u64,
+    
+    pub total_wagered: u64,
+    
+    /// Bitmask of allowed game types (`GameType::bit`)
+    pub allowed_games: u8,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl SessionKey {
+    /// Check a bet against the key's limits and count it towards `max_total_wager`
+    pub fn authorize_bet(&mut self, game_type: GameType, bet_amount: u64, now: i64) -> Result<()> {
+        require!(now < self.expires_at, CasinoError::SessionKeyExpired);
+        require!(
+            self.allowed_games & game_type.bit() != 0,
+            CasinoError::SessionKeyNotAllowed
+        );
+        let total_wagered = self.total_wagered
+            .checked_add(bet_amount)
+            .ok_or(CasinoError::Overflow)?;
+        require!(
+            total_wagered <= self.max_total_wager,
+            CasinoError::SessionKeyNotAllowed
+        );
+        self.total_wagered = total_wagered;
+        Ok(())
+    }
+}
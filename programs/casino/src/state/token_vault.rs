@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use crate::errors::CasinoError;
+
+/// Bankroll for bets in one SPL Token or Token-2022 mint, at
+/// `[TOKEN_VAULT_SEED, casino, mint]`. Tokens are held in this PDA's associated
+/// token account; amounts are in the mint's base units.
+#[account]
+#[derive(InitSpace)]
+pub struct TokenVault {
+    pub mint: Pubkey,
+    
+    /// Associated token account of this PDA for `mint`
+    pub vault_token_account: Pubkey,
+    
+    pub decimals: u8,
+    
+    /// Token program owning the mint (SPL Token or Token-2022)
+    pub token_program: Pubkey,
+    
+    /// Transfer hook program the mint may use (Token-2022 only). Hook
+    /// accounts are passed as remaining accounts to every token instruction.
+    pub transfer_hook_program: Option<Pubkey>,
+    
+    pub min_bet: u64,
+    pub max_bet: u64,
+    
+    /// Accepts new bets (the casino must also be active)
+    pub is_active: bool,
+    
+    /// Sum of the maximum payouts of this mint's pending sessions
+    pub pending_liability: u64,
+    
+    /// Sum of this mint's resolved wins not yet claimed
+    pub unclaimed_payouts: u64,
+    
+    pub total_volume: u64,
+    pub total_payouts: u64,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl TokenVault {
+    pub fn validate_bet_amount(&self, amount: u64) -> Result<()> {
+        require!(
+            amount >= self.min_bet && amount <= self.max_bet,
+            CasinoError::InvalidBetAmount
+        );
+        Ok(())
+    }
+    
+    /// Same rule as `CasinoConfig::reserve_liability`, against the token balance
+    pub fn reserve_liability(&mut self, max_payout: u64, vault_balance: u64) -> Result<()> {
+        let pending_liability = self.pending_liability
+            .checked_add(max_payout)
+            .ok_or(CasinoError::Overflow)?;
+        let total_liabilities = pending_liability
+            .checked_add(self.unclaimed_payouts)
+            .ok_or(CasinoError::Overflow)?;
+        require!(
+            vault_balance >= total_liabilities,
+            CasinoError::InsufficientVaultLiquidity
+        );
+        self.pending_liability = pending_liability;
+        Ok(())
+    }
+    
+    /// Pending sessions at their maximum payout plus unclaimed wins
+    pub fn total_liabilities(&self) -> Result<u64> {
+        self.pending_liability
+            .checked_add(self.unclaimed_payouts)
+            .ok_or(CasinoError::Overflow.into())
+    }
+    
+    pub fn release_liability(&mut self, max_payout: u64) -> Result<()> {
+        self.pending_liability = self.pending_liability
+            .checked_sub(max_payout)
+            .ok_or(CasinoError::Overflow)?;
+        Ok(())
+    }
+    
+    pub fn add_unclaimed_payout(&mut self, payout: u64) -> Result<()> {
+        self.unclaimed_payouts = self.unclaimed_payouts
+            .checked_add(payout)
+            .ok_or(CasinoError::Overflow)?;
+        Ok(())
+    }
+    
+    pub fn remove_unclaimed_payout(&mut self, payout: u64) -> Result<()> {
+        self.unclaimed_payouts = self.unclaimed_payouts
+            .checked_sub(payout)
+            .ok_or(CasinoError::Overflow)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,17 @@
+use anchor_lang::prelude::*;
+
+/// Allowlist entry: `withdraw_treasury` can only pay `destination` while
+/// this account exists at `[TREASURY_DESTINATION_SEED, casino, destination]`
+#[account]
+#[derive(InitSpace)]
+pub struct TreasuryDestination {
+    pub destination: Pubkey,
+    
+    /// Lifetime lamports withdrawn to this destination
+    pub total_withdrawn: u64,
+    
+    pub added_at: i64,
+    
+    /// PDA bump
+    pub bump: u8,
+}
@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use crate::errors::CasinoError;
+use super::MAX_RAKEBACK_BP;
+
+/// Upper bound on `VipConfig::tiers` (keep in sync with `max_len`)
+pub const MAX_VIP_TIERS: usize = 8;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub struct VipTier {
+    /// Lifetime lamports wagered needed to reach the tier
+    pub min_wagered: u64,
+    /// Share of the house edge of each bet paid back to the player, in basis points
+    pub rakeback_bp: u16,
+}
+
+/// VIP tier table. Tier `n` (1-based, 0 = no tier) is `tiers[n - 1]`;
+/// thresholds strictly increase.
+#[account]
+#[derive(InitSpace)]
+pub struct VipConfig {
+    #[max_len(8)]
+    pub tiers: Vec<VipTier>,
+    
+    /// PDA bump
+    pub bump: u8,
+}
+
+impl VipConfig {
+    /// At most `MAX_VIP_TIERS` tiers with strictly increasing thresholds and
+    /// rakeback within `MAX_RAKEBACK_BP`
+    pub fn validate_tiers(tiers: &[VipTier]) -> Result<()> {
+        require!(tiers.len() <= MAX_VIP_TIERS, CasinoError::InvalidVipTiers);
+        
+        for (i, tier) in tiers.iter().enumerate() {
+            require!(tier.rakeback_bp as u64 <= MAX_RAKEBACK_BP, CasinoError::InvalidVipTiers);
+            if i > 0 {
+                require!(tier.min_wagered > tiers[i - 1].min_wagered, CasinoError::InvalidVipTiers);
+            }
+        }
+        Ok(())
+    }
+    
+    /// Highest tier whose threshold `total_wagered` reaches
+    pub fn tier_for(&self, total_wagered: u64) -> u8 {
+        self.tiers
+            .iter()
+            .take_while(|tier| tier.min_wagered <= total_wagered)
+            .count() as u8
+    }
+    
+    /// Rakeback of `tier`, in basis points (0 for no tier)
+    pub fn rakeback_bp(&self, tier: u8) -> u64 {
+        tier.checked_sub(1)
+            .and_then(|index| self.tiers.get(index as usize))
+            .map_or(0, |tier| tier.rakeback_bp as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tier(min_wagered: u64, rakeback_bp: u16) -> VipTier {
+        VipTier { min_wagered, rakeback_bp }
+    }
+
+    fn vip_config(tiers: Vec<VipTier>) -> VipConfig {
+        VipConfig { tiers, bump: 0 }
+    }
+
+    fn assert_invalid(tiers: &[VipTier]) {
+        assert_eq!(
+            VipConfig::validate_tiers(tiers).unwrap_err(),
+            CasinoError::InvalidVipTiers.into()
+        );
+    }
+
+    #[test]
+    fn tier_thresholds_are_inclusive() {
+        let config = vip_config(vec![tier(100, 1_000), tier(1_000, 2_000), tier(10_000, 3_000)]);
+        assert_eq!(config.tier_for(0), 0);
+        assert_eq!(config.tier_for(99), 0);
+        assert_eq!(config.tier_for(100), 1);
+        assert_eq!(config.tier_for(999), 1);
+        assert_eq!(config.tier_for(1_000), 2);
+        assert_eq!(config.tier_for(u64::MAX), 3);
+
+        assert_eq!(config.rakeback_bp(0), 0);
+        assert_eq!(config.rakeback_bp(2), 2_000);
+        // A tier above the table (after it shrank) pays nothing
+        assert_eq!(config.rakeback_bp(4), 0);
+    }
+
+    #[test]
+    fn empty_table_has_no_tiers() {
+        let config = vip_config(Vec::new());
+        VipConfig::validate_tiers(&config.tiers).unwrap();
+        assert_eq!(config.tier_for(u64::MAX), 0);
+        assert_eq!(config.rakeback_bp(1), 0);
+    }
+
+    #[test]
+    fn unsorted_tables_are_rejected() {
+        VipConfig::validate_tiers(&[tier(0, 0), tier(100, 1_000)]).unwrap();
+        assert_invalid(&[tier(1_000, 1_000), tier(100, 2_000)]);
+        assert_invalid(&[tier(100, 1_000), tier(100, 2_000)]);
+    }
+
+    #[test]
+    fn table_size_and_rakeback_are_bounded() {
+        let max: Vec<_> = (1..=MAX_VIP_TIERS as u64).map(|n| tier(n, MAX_RAKEBACK_BP as u16)).collect();
+        VipConfig::validate_tiers(&max).unwrap();
+
+        let too_many: Vec<_> = (1..=MAX_VIP_TIERS as u64 + 1).map(|n| tier(n, 0)).collect();
+        assert_invalid(&too_many);
+        assert_invalid(&[tier(100, MAX_RAKEBACK_BP as u16 + 1)]);
+    }
+}
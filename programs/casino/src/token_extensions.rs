@@ -0,0 +1,231 @@
+//! Token-2022 support for token vaults.
+//!
+//! Mint extensions are read straight from the mint's TLV data: the
+//! spl-token-2022 version pinned by anchor-spl 0.28 fails to parse mints
+//! carrying extensions newer than it (transfer hooks, metadata, ...).
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::AccountMeta;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::token_interface::{spl_token_2022, TransferChecked};
+use crate::errors::CasinoError;
+
+/// Base mint state is padded to the size of a token account before the
+/// account-type byte and the extension TLV entries
+const ACCOUNT_TYPE_OFFSET: usize = 165;
+const TLV_OFFSET: usize = ACCOUNT_TYPE_OFFSET + 1;
+const ACCOUNT_TYPE_MINT: u8 = 1;
+
+/// Extension type discriminants (`spl_token_2022::extension::ExtensionType`)
+const EXTENSION_UNINITIALIZED: u16 = 0;
+const EXTENSION_NON_TRANSFERABLE: u16 = 9;
+const EXTENSION_PERMANENT_DELEGATE: u16 = 12;
+const EXTENSION_TRANSFER_HOOK: u16 = 14;
+
+/// Reject mints whose extensions would let someone other than the vault move
+/// or lock its tokens: non-transferable mints, a permanent delegate, or a
+/// transfer hook program other than `allowed_transfer_hook`.
+///
+/// Transfer fees and other extensions are fine; inbound bets are measured by
+/// what actually arrives in the vault.
+pub fn validate_mint(mint: &AccountInfo, allowed_transfer_hook: Option<Pubkey>) -> Result<()> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(());
+    }
+    
+    let data = mint.try_borrow_data()?;
+    if data.len() <= ACCOUNT_TYPE_OFFSET {
+        return Ok(());
+    }
+    require!(
+        data[ACCOUNT_TYPE_OFFSET] == ACCOUNT_TYPE_MINT,
+        CasinoError::UnsupportedMintExtension
+    );
+    
+    let mut offset = TLV_OFFSET;
+    while offset + 4 <= data.len() {
+        let extension_type = u16::from_le_bytes([data[offset], data[offset + 1]]);
+        let length = u16::from_le_bytes([data[offset + 2], data[offset + 3]]) as usize;
+        if extension_type == EXTENSION_UNINITIALIZED {
+            break;
+        }
+        let value = data
+            .get(offset + 4..offset + 4 + length)
+            .ok_or(CasinoError::UnsupportedMintExtension)?;
+        
+        match extension_type {
+            EXTENSION_NON_TRANSFERABLE => {
+                return err!(CasinoError::UnsupportedMintExtension);
+            },
+            EXTENSION_PERMANENT_DELEGATE => {
+                // delegate: OptionalNonZeroPubkey
+                require!(
+                    value.iter().all(|byte| *byte == 0),
+                    CasinoError::UnsupportedMintExtension
+                );
+            },
+            EXTENSION_TRANSFER_HOOK => {
+                // authority: OptionalNonZeroPubkey | program_id: OptionalNonZeroPubkey
+                let program_id = value.get(32..64).ok_or(CasinoError::UnsupportedMintExtension)?;
+                if program_id.iter().any(|byte| *byte != 0) {
+                    require!(
+                        allowed_transfer_hook.is_some_and(|allowed| allowed.as_ref() == program_id),
+                        CasinoError::TransferHookNotAllowed
+                    );
+                }
+            },
+            _ => {},
+        }
+        
+        offset += 4 + length;
+    }
+    
+    Ok(())
+}
+
+/// `transfer_checked` for either token program. Unlike the anchor-spl helper
+/// it forwards the context's remaining accounts, which Token-2022 needs to
+/// execute a mint's transfer hook.
+pub fn transfer_checked<'info>(
+    ctx: CpiContext<'_, '_, '_, 'info, TransferChecked<'info>>,
+    amount: u64,
+    decimals: u8,
+) -> Result<()> {
+    let mut ix = spl_token_2022::instruction::transfer_checked(
+        ctx.program.key,
+        ctx.accounts.from.key,
+        ctx.accounts.mint.key,
+        ctx.accounts.to.key,
+        ctx.accounts.authority.key,
+        &[],
+        amount,
+        decimals,
+    )?;
+    
+    let mut account_infos = vec![
+        ctx.accounts.from,
+        ctx.accounts.mint,
+        ctx.accounts.to,
+        ctx.accounts.authority,
+    ];
+    for account in ctx.remaining_accounts {
+        ix.accounts.push(AccountMeta {
+            pubkey: *account.key,
+            is_signer: account.is_signer,
+            is_writable: account.is_writable,
+        });
+        account_infos.push(account);
+    }
+    
+    invoke_signed(&ix, &account_infos, ctx.signer_seeds).map_err(Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXTENSION_TRANSFER_FEE_CONFIG: u16 = 1;
+
+    /// Token-2022 mint data carrying the given `(type, value)` TLV entries
+    fn mint_data(extensions: &[(u16, Vec<u8>)]) -> Vec<u8> {
+        let mut data = vec![0u8; ACCOUNT_TYPE_OFFSET];
+        data.push(ACCOUNT_TYPE_MINT);
+        for (extension_type, value) in extensions {
+            data.extend_from_slice(&extension_type.to_le_bytes());
+            data.extend_from_slice(&(value.len() as u16).to_le_bytes());
+            data.extend_from_slice(value);
+        }
+        data
+    }
+
+    fn transfer_hook(program_id: Pubkey) -> (u16, Vec<u8>) {
+        let mut value = Pubkey::new_unique().to_bytes().to_vec();
+        value.extend_from_slice(program_id.as_ref());
+        (EXTENSION_TRANSFER_HOOK, value)
+    }
+
+    fn validate(mut data: Vec<u8>, owner: Pubkey, allowed_transfer_hook: Option<Pubkey>) -> Result<()> {
+        let key = Pubkey::new_unique();
+        let mut lamports = 0;
+        let mint = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &owner, false, 0);
+        validate_mint(&mint, allowed_transfer_hook)
+    }
+
+    fn validate_2022(data: Vec<u8>, allowed_transfer_hook: Option<Pubkey>) -> Result<()> {
+        validate(data, spl_token_2022::ID, allowed_transfer_hook)
+    }
+
+    #[test]
+    fn accepts_plain_and_fee_mints() {
+        validate_2022(mint_data(&[]), None).unwrap();
+        validate_2022(mint_data(&[(EXTENSION_TRANSFER_FEE_CONFIG, vec![7; 108])]), None).unwrap();
+    }
+
+    #[test]
+    fn skips_legacy_token_mints() {
+        let data = mint_data(&[(EXTENSION_NON_TRANSFERABLE, vec![])]);
+        validate(data, anchor_spl::token::ID, None).unwrap();
+    }
+
+    #[test]
+    fn rejects_non_transferable_mints() {
+        assert_eq!(
+            validate_2022(mint_data(&[(EXTENSION_NON_TRANSFERABLE, vec![])]), None).unwrap_err(),
+            CasinoError::UnsupportedMintExtension.into()
+        );
+    }
+
+    #[test]
+    fn rejects_a_permanent_delegate_only_when_set() {
+        validate_2022(mint_data(&[(EXTENSION_PERMANENT_DELEGATE, vec![0; 32])]), None).unwrap();
+
+        let delegate = Pubkey::new_unique().to_bytes().to_vec();
+        assert_eq!(
+            validate_2022(mint_data(&[(EXTENSION_PERMANENT_DELEGATE, delegate)]), None).unwrap_err(),
+            CasinoError::UnsupportedMintExtension.into()
+        );
+    }
+
+    #[test]
+    fn accepts_only_the_allowlisted_transfer_hook() {
+        let hook = Pubkey::new_unique();
+        validate_2022(mint_data(&[transfer_hook(hook)]), Some(hook)).unwrap();
+        validate_2022(mint_data(&[transfer_hook(Pubkey::default())]), None).unwrap();
+
+        assert_eq!(
+            validate_2022(mint_data(&[transfer_hook(hook)]), None).unwrap_err(),
+            CasinoError::TransferHookNotAllowed.into()
+        );
+        assert_eq!(
+            validate_2022(mint_data(&[transfer_hook(hook)]), Some(Pubkey::new_unique())).unwrap_err(),
+            CasinoError::TransferHookNotAllowed.into()
+        );
+    }
+
+    #[test]
+    fn rejects_truncated_tlv_entries() {
+        let mut data = mint_data(&[(EXTENSION_TRANSFER_FEE_CONFIG, vec![7; 108])]);
+        data.truncate(data.len() - 1);
+        assert_eq!(
+            validate_2022(data, None).unwrap_err(),
+            CasinoError::UnsupportedMintExtension.into()
+        );
+
+        // A transfer hook entry too short to hold the program ID
+        let data = mint_data(&[(EXTENSION_TRANSFER_HOOK, vec![1; 40])]);
+        assert_eq!(
+            validate_2022(data, None).unwrap_err(),
+            CasinoError::UnsupportedMintExtension.into()
+        );
+    }
+
+    #[test]
+    fn rejects_accounts_that_are_not_mints() {
+        let mut data = mint_data(&[]);
+        data[ACCOUNT_TYPE_OFFSET] = 2;
+        assert_eq!(
+            validate_2022(data, None).unwrap_err(),
+            CasinoError::UnsupportedMintExtension.into()
+        );
+    }
+}
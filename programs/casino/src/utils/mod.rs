@@ -1,4 +1,18 @@
-use solana_program::hash::hash;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+/// Deserialize a program account passed at its fixed PDA address, or `None`
+/// if it has not been created yet. Lets instructions always take the address
+/// (so callers cannot skip it) while the account itself stays optional.
+pub fn load_if_initialized<'info, T>(info: &AccountInfo<'info>) -> Result<Option<Account<'info, T>>>
+where
+    T: AccountSerialize + AccountDeserialize + Owner + Clone,
+{
+    if info.owner != &crate::ID || info.data_is_empty() {
+        return Ok(None);
+    }
+    Account::try_from(info).map(Some)
+}
 
 /// Generate provably fair outcome using Solana's built-in SHA256
 pub fn generate_outcome(server_seed: &str, client_seed: &str, nonce: u64) -> [u8; 32] {
@@ -8,14 +22,33 @@ pub fn generate_outcome(server_seed: &str, client_seed: &str, nonce: u64) -> [u8
 }
 
 /// Verify server seed hash matches
-pub fn verify_server_seed_hash(server_seed: &str, hash_hex: &str) -> bool {
-    let hash_result = hash(server_seed.as_bytes());
-    let computed_hash = hash_result.to_string();
-    computed_hash == hash_hex
+pub fn verify_server_seed_hash(server_seed: &str, commitment: &[u8; 32]) -> bool {
+    hash_server_seed(server_seed) == *commitment
 }
 
 /// Generate server seed hash
-pub fn hash_server_seed(server_seed: &str) -> String {
-    let hash_result = hash(server_seed.as_bytes());
-    hash_result.to_string()
+pub fn hash_server_seed(server_seed: &str) -> [u8; 32] {
+    hash(server_seed.as_bytes()).to_bytes()
+}
+
+/// Commitment to the next seed of a hash chain.
+///
+/// Seeds are 64-char lowercase hex strings where each seed is the hex of the
+/// SHA256 of the one revealed after it, so the revealed seed decoded from hex
+/// is the hash of its successor. Returns `None` if the seed is not valid hex.
+pub fn next_commitment(server_seed: &str) -> Option<[u8; 32]> {
+    let bytes = server_seed.as_bytes();
+    if bytes.len() != 64 {
+        return None;
+    }
+    let nibble = |c: u8| match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        b'a'..=b'f' => Some(c - b'a' + 10),
+        _ => None,
+    };
+    let mut commitment = [0u8; 32];
+    for (i, pair) in bytes.chunks(2).enumerate() {
+        commitment[i] = nibble(pair[0])? << 4 | nibble(pair[1])?;
+    }
+    Some(commitment)
 }
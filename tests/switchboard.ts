@@ -0,0 +1,175 @@
+// Switchboard On-Demand bets against fake randomness accounts.
+//
+// Needs the program built with mock randomness, which also lets
+// `mock_switchboard_randomness` commit and reveal fake randomness accounts:
+//   anchor test -- --features mock-vrf
+
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Program, web3 } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { createHash } from "crypto";
+import { Casino } from "../target/types/casino";
+
+const { PublicKey, Keypair, SystemProgram, LAMPORTS_PER_SOL } = web3;
+
+const BET = 0.01 * LAMPORTS_PER_SOL;
+
+describe("switchboard randomness", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.Casino as Program<Casino>;
+  const connection = provider.connection;
+  const authority = provider.wallet.publicKey;
+
+  const player = Keypair.generate();
+
+  const [casino] = PublicKey.findProgramAddressSync([Buffer.from("casino")], program.programId);
+  const [vault] = PublicKey.findProgramAddressSync([Buffer.from("vault"), casino.toBuffer()], program.programId);
+  const [treasury] = PublicKey.findProgramAddressSync([Buffer.from("treasury"), casino.toBuffer()], program.programId);
+  const [playerLimits] = PublicKey.findProgramAddressSync(
+    [Buffer.from("player_limits"), casino.toBuffer(), player.publicKey.toBuffer()],
+    program.programId,
+  );
+  const [playerProfile] = PublicKey.findProgramAddressSync(
+    [Buffer.from("player"), casino.toBuffer(), player.publicKey.toBuffer()],
+    program.programId,
+  );
+  const [vipConfig] = PublicKey.findProgramAddressSync(
+    [Buffer.from("vip_config"), casino.toBuffer()],
+    program.programId,
+  );
+
+  const sessionPda = (gameId: BN) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("session"), player.publicKey.toBuffer(), gameId.toArrayLike(Buffer, "le", 8)],
+      program.programId,
+    )[0];
+
+  async function setSource(source: object) {
+    await program.methods.setRandomnessSource(source as any).accounts({ casino, authority }).rpc();
+  }
+
+  // Commits a new fake randomness account (`reveal` null) or reveals an existing one
+  async function mockRandomness(randomness: web3.Keypair, reveal: number[] | null) {
+    await program.methods
+      .mockSwitchboardRandomness(reveal)
+      .accounts({
+        randomnessAccount: randomness.publicKey,
+        payer: authority,
+        systemProgram: SystemProgram.programId,
+      })
+      .signers(reveal ? [] : [randomness])
+      .rpc();
+  }
+
+  async function placeBet(randomnessAccount: web3.PublicKey): Promise<web3.PublicKey> {
+    const { totalGames } = await program.account.casinoConfig.fetch(casino);
+    const session = sessionPda(totalGames);
+    await program.methods
+      .placeBet({ coinFlip: {} }, 0, new BN(BET), null)
+      .accounts({
+        casino,
+        session,
+        vault,
+        player: player.publicKey,
+        playerLimits,
+        randomnessAccount,
+        seedChain: null,
+        systemProgram: SystemProgram.programId,
+        rent: web3.SYSVAR_RENT_PUBKEY,
+        clock: web3.SYSVAR_CLOCK_PUBKEY,
+      })
+      .signers([player])
+      .rpc();
+    return session;
+  }
+
+  async function settle(session: web3.PublicKey, randomnessAccount: web3.PublicKey) {
+    await program.methods
+      .settleWithVrf()
+      .accounts({
+        casino,
+        session,
+        vault,
+        player: player.publicKey,
+        caller: player.publicKey,
+        settlerRole: null,
+        randomnessAccount,
+        seedChain: null,
+        tokenVault: null,
+        playerBalance: null,
+        playerProfile,
+        referrerProfile: null,
+        vipConfig,
+        playerLimits,
+        instructionsSysvar: web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        slotHashes: web3.SYSVAR_SLOT_HASHES_PUBKEY,
+        systemProgram: SystemProgram.programId,
+        clock: web3.SYSVAR_CLOCK_PUBKEY,
+      })
+      .signers([player])
+      .rpc();
+  }
+
+  before(async () => {
+    await connection.confirmTransaction(
+      await connection.requestAirdrop(player.publicKey, 2 * LAMPORTS_PER_SOL),
+    );
+
+    if (!(await connection.getAccountInfo(casino))) {
+      await program.methods
+        .initialize(new BN(0.01 * LAMPORTS_PER_SOL), new BN(0.1 * LAMPORTS_PER_SOL), new BN(LAMPORTS_PER_SOL), authority)
+        .accounts({ casino, vault, treasury, authority, systemProgram: SystemProgram.programId })
+        .rpc();
+    }
+
+    await setSource({ switchboard: {} });
+  });
+
+  after(async () => {
+    await setSource({ mock: {} });
+  });
+
+  it("binds a recent commitment and settles once it is revealed", async () => {
+    const randomness = Keypair.generate();
+    await mockRandomness(randomness, null);
+
+    // Committed in an earlier transaction, a slot or more before the bet
+    const session = await placeBet(randomness.publicKey);
+    const { randomnessRequest } = await program.account.gameSession.fetch(session);
+    expect(randomnessRequest.switchboardRequest!.toBase58()).to.equal(randomness.publicKey.toBase58());
+
+    try {
+      await settle(session, randomness.publicKey);
+      expect.fail("settled before the reveal");
+    } catch (err) {
+      expect(`${err}`).to.include("RandomnessNotRevealed");
+    }
+
+    const value = new Array(32).fill(0);
+    await mockRandomness(randomness, value);
+    await settle(session, randomness.publicKey);
+
+    // The session's value is sha256(revealed value || session); its first
+    // word decides the flip (0 = heads, a win for choice 0)
+    const sessionValue = createHash("sha256").update(Buffer.from(value)).update(session.toBuffer()).digest();
+    const isWin = sessionValue.readBigUInt64LE(0) % 2n === 0n;
+
+    const { result } = await program.account.gameSession.fetch(session);
+    expect(result!.isWin).to.equal(isWin);
+    expect(result!.payout.toNumber()).to.equal(isWin ? (BET * 19600) / 10000 : 0);
+  });
+
+  it("rejects a randomness account that was already revealed", async () => {
+    const randomness = Keypair.generate();
+    await mockRandomness(randomness, null);
+    await mockRandomness(randomness, new Array(32).fill(7));
+
+    try {
+      await placeBet(randomness.publicKey);
+      expect.fail("bet bound to a revealed value");
+    } catch (err) {
+      expect(`${err}`).to.include("StaleRandomnessCommitment");
+    }
+  });
+});
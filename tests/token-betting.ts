@@ -0,0 +1,343 @@
+// Token betting against a mint created by the test.
+//
+// Needs the program built with mock randomness so bets can be settled with
+// a chosen value:  anchor test -- --features mock-vrf
+
+import * as anchor from "@coral-xyz/anchor";
+import { BN, Program, web3 } from "@coral-xyz/anchor";
+import { expect } from "chai";
+import { Casino } from "../target/types/casino";
+
+const { PublicKey, Keypair, SystemProgram, Transaction, LAMPORTS_PER_SOL } = web3;
+const TOKEN_PROGRAM_ID = anchor.utils.token.TOKEN_PROGRAM_ID;
+const ASSOCIATED_TOKEN_PROGRAM_ID = anchor.utils.token.ASSOCIATED_PROGRAM_ID;
+
+const MINT_SIZE = 82;
+const DECIMALS = 6;
+const UNIT = 10 ** DECIMALS;
+
+// --- Minimal SPL token helpers (no @solana/spl-token dependency) ---
+
+function initializeMint2Ix(mint: web3.PublicKey, authority: web3.PublicKey): web3.TransactionInstruction {
+  const data = Buffer.alloc(67);
+  data.writeUInt8(20, 0); // InitializeMint2
+  data.writeUInt8(DECIMALS, 1);
+  authority.toBuffer().copy(data, 2);
+  data.writeUInt8(0, 34); // no freeze authority
+  return new web3.TransactionInstruction({
+    programId: TOKEN_PROGRAM_ID,
+    keys: [{ pubkey: mint, isSigner: false, isWritable: true }],
+    data,
+  });
+}
+
+function createAtaIx(
+  payer: web3.PublicKey,
+  ata: web3.PublicKey,
+  owner: web3.PublicKey,
+  mint: web3.PublicKey,
+): web3.TransactionInstruction {
+  return new web3.TransactionInstruction({
+    programId: ASSOCIATED_TOKEN_PROGRAM_ID,
+    keys: [
+      { pubkey: payer, isSigner: true, isWritable: true },
+      { pubkey: ata, isSigner: false, isWritable: true },
+      { pubkey: owner, isSigner: false, isWritable: false },
+      { pubkey: mint, isSigner: false, isWritable: false },
+      { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+      { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+    ],
+    data: Buffer.from([1]), // CreateIdempotent
+  });
+}
+
+function mintToIx(
+  mint: web3.PublicKey,
+  destination: web3.PublicKey,
+  authority: web3.PublicKey,
+  amount: number,
+): web3.TransactionInstruction {
+  const data = Buffer.alloc(9);
+  data.writeUInt8(7, 0); // MintTo
+  data.writeBigUInt64LE(BigInt(amount), 1);
+  return new web3.TransactionInstruction({
+    programId: TOKEN_PROGRAM_ID,
+    keys: [
+      { pubkey: mint, isSigner: false, isWritable: true },
+      { pubkey: destination, isSigner: false, isWritable: true },
+      { pubkey: authority, isSigner: true, isWritable: false },
+    ],
+    data,
+  });
+}
+
+async function tokenBalance(connection: web3.Connection, account: web3.PublicKey): Promise<number> {
+  const balance = await connection.getTokenAccountBalance(account);
+  return Number(balance.value.amount);
+}
+
+describe("token betting", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.Casino as Program<Casino>;
+  const connection = provider.connection;
+  const authority = provider.wallet.publicKey;
+
+  const mintKeypair = Keypair.generate();
+  const mint = mintKeypair.publicKey;
+  const player = Keypair.generate();
+
+  const [casino] = PublicKey.findProgramAddressSync([Buffer.from("casino")], program.programId);
+  const [vault] = PublicKey.findProgramAddressSync([Buffer.from("vault"), casino.toBuffer()], program.programId);
+  const [treasury] = PublicKey.findProgramAddressSync([Buffer.from("treasury"), casino.toBuffer()], program.programId);
+  const [tokenVault] = PublicKey.findProgramAddressSync(
+    [Buffer.from("token_vault"), casino.toBuffer(), mint.toBuffer()],
+    program.programId,
+  );
+  const vaultTokenAccount = anchor.utils.token.associatedAddress({ mint, owner: tokenVault });
+  const authorityTokenAccount = anchor.utils.token.associatedAddress({ mint, owner: authority });
+  const playerTokenAccount = anchor.utils.token.associatedAddress({ mint, owner: player.publicKey });
+
+  const sessionPda = (gameId: BN) =>
+    PublicKey.findProgramAddressSync(
+      [Buffer.from("session"), player.publicKey.toBuffer(), gameId.toArrayLike(Buffer, "le", 8)],
+      program.programId,
+    )[0];
+
+  const [playerLimits] = PublicKey.findProgramAddressSync(
+    [Buffer.from("player_limits"), casino.toBuffer(), player.publicKey.toBuffer()],
+    program.programId,
+  );
+
+  const [playerProfile] = PublicKey.findProgramAddressSync(
+    [Buffer.from("player"), casino.toBuffer(), player.publicKey.toBuffer()],
+    program.programId,
+  );
+  const [vipConfig] = PublicKey.findProgramAddressSync(
+    [Buffer.from("vip_config"), casino.toBuffer()],
+    program.programId,
+  );
+
+  async function placeBet(betAmount: number): Promise<web3.PublicKey> {
+    const { totalGames } = await program.account.casinoConfig.fetch(casino);
+    const session = sessionPda(totalGames);
+    await program.methods
+      .placeTokenBet({ coinFlip: {} }, 0, new BN(betAmount), null)
+      .accounts({
+        casino,
+        tokenVault,
+        session,
+        mint,
+        vaultTokenAccount,
+        playerTokenAccount,
+        player: player.publicKey,
+        playerLimits,
+        randomnessAccount: null,
+        seedChain: null,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+        clock: web3.SYSVAR_CLOCK_PUBKEY,
+      })
+      .signers([player])
+      .rpc();
+    return session;
+  }
+
+  async function settle(session: web3.PublicKey, randomValue: number[]) {
+    await program.methods
+      .fulfillRandomness(randomValue, null)
+      .accounts({
+        casino,
+        session,
+        vault,
+        player: player.publicKey,
+        caller: player.publicKey,
+        settlerRole: null,
+        randomnessAccount: null,
+        seedChain: null,
+        tokenVault,
+        playerBalance: null,
+        playerProfile,
+        referrerProfile: null,
+        vipConfig,
+        playerLimits,
+        instructionsSysvar: web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+        slotHashes: web3.SYSVAR_SLOT_HASHES_PUBKEY,
+        systemProgram: SystemProgram.programId,
+        clock: web3.SYSVAR_CLOCK_PUBKEY,
+      })
+      .signers([player])
+      .rpc();
+  }
+
+  before(async () => {
+    await connection.confirmTransaction(
+      await connection.requestAirdrop(player.publicKey, 2 * LAMPORTS_PER_SOL),
+    );
+
+    if (!(await connection.getAccountInfo(casino))) {
+      await program.methods
+        .initialize(new BN(0.01 * LAMPORTS_PER_SOL), new BN(0.1 * LAMPORTS_PER_SOL), new BN(LAMPORTS_PER_SOL), authority)
+        .accounts({ casino, vault, treasury, authority, systemProgram: SystemProgram.programId })
+        .rpc();
+    }
+
+    const rent = await connection.getMinimumBalanceForRentExemption(MINT_SIZE);
+    const tx = new Transaction().add(
+      SystemProgram.createAccount({
+        fromPubkey: authority,
+        newAccountPubkey: mint,
+        lamports: rent,
+        space: MINT_SIZE,
+        programId: TOKEN_PROGRAM_ID,
+      }),
+      initializeMint2Ix(mint, authority),
+      createAtaIx(authority, authorityTokenAccount, authority, mint),
+      createAtaIx(authority, playerTokenAccount, player.publicKey, mint),
+      mintToIx(mint, authorityTokenAccount, authority, 10_000 * UNIT),
+      mintToIx(mint, playerTokenAccount, authority, 100 * UNIT),
+    );
+    await provider.sendAndConfirm(tx, [mintKeypair]);
+  });
+
+  it("initializes a token vault with its own bankroll and limits", async () => {
+    await program.methods
+      .initTokenVault(new BN(UNIT), new BN(10 * UNIT), new BN(1_000 * UNIT), null)
+      .accounts({
+        casino,
+        tokenVault,
+        mint,
+        vaultTokenAccount,
+        authorityTokenAccount,
+        authority,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const state = await program.account.tokenVault.fetch(tokenVault);
+    expect(state.mint.toBase58()).to.equal(mint.toBase58());
+    expect(state.maxBet.toNumber()).to.equal(10 * UNIT);
+    expect(await tokenBalance(connection, vaultTokenAccount)).to.equal(1_000 * UNIT);
+  });
+
+  it("rejects bets outside the mint's limits", async () => {
+    try {
+      await placeBet(11 * UNIT);
+      expect.fail("bet above max_bet was accepted");
+    } catch (err) {
+      expect(`${err}`).to.include("InvalidBetAmount");
+    }
+  });
+
+  it("pays a winning token bet through claim_token_payout", async () => {
+    const bet = 5 * UNIT;
+    const session = await placeBet(bet);
+    expect(await tokenBalance(connection, playerTokenAccount)).to.equal(95 * UNIT);
+
+    // All-zero value: the first draw is 0 (heads), a win for choice 0
+    await settle(session, new Array(32).fill(0));
+
+    const { result } = await program.account.gameSession.fetch(session);
+    const payout = (bet * 19600) / 10000;
+    expect(result!.isWin).to.equal(true);
+    expect(result!.payout.toNumber()).to.equal(payout);
+    expect((await program.account.tokenVault.fetch(tokenVault)).unclaimedPayouts.toNumber()).to.equal(payout);
+
+    await program.methods
+      .claimTokenPayout()
+      .accounts({
+        casino,
+        session,
+        tokenVault,
+        mint,
+        vaultTokenAccount,
+        playerTokenAccount,
+        player: player.publicKey,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([player])
+      .rpc();
+
+    expect(await tokenBalance(connection, playerTokenAccount)).to.equal(95 * UNIT + payout);
+    const state = await program.account.tokenVault.fetch(tokenVault);
+    expect(state.unclaimedPayouts.toNumber()).to.equal(0);
+    expect(state.pendingLiability.toNumber()).to.equal(0);
+  });
+
+  it("keeps a losing token bet in the vault", async () => {
+    const before = await tokenBalance(connection, vaultTokenAccount);
+    const session = await placeBet(2 * UNIT);
+
+    // First word 1: tails, a loss for choice 0
+    const randomValue = new Array(32).fill(0);
+    randomValue[0] = 1;
+    await settle(session, randomValue);
+
+    const { result } = await program.account.gameSession.fetch(session);
+    expect(result!.isWin).to.equal(false);
+    expect(await tokenBalance(connection, vaultTokenAccount)).to.equal(before + 2 * UNIT);
+    expect((await program.account.tokenVault.fetch(tokenVault)).pendingLiability.toNumber()).to.equal(0);
+  });
+
+  it("withdraws bankroll down to the vault's liabilities", async () => {
+    const [treasurerRole] = PublicKey.findProgramAddressSync(
+      [Buffer.from("role"), casino.toBuffer(), Buffer.from([1]), authority.toBuffer()],
+      program.programId,
+    );
+    const [treasuryDestination] = PublicKey.findProgramAddressSync(
+      [Buffer.from("treasury_destination"), casino.toBuffer(), authority.toBuffer()],
+      program.programId,
+    );
+    if (!(await connection.getAccountInfo(treasurerRole))) {
+      await program.methods
+        .grantRole({ treasurer: {} }, authority)
+        .accounts({ casino, roleAssignment: treasurerRole, authority, systemProgram: SystemProgram.programId })
+        .rpc();
+    }
+    if (!(await connection.getAccountInfo(treasuryDestination))) {
+      await program.methods
+        .addTreasuryDestination(authority)
+        .accounts({ casino, treasuryDestination, authority, systemProgram: SystemProgram.programId })
+        .rpc();
+    }
+
+    const withdraw = (amount: number) =>
+      program.methods
+        .withdrawTokenVault(new BN(amount))
+        .accounts({
+          casino,
+          tokenVault,
+          mint,
+          vaultTokenAccount,
+          treasuryDestination,
+          destination: authorityTokenAccount,
+          treasurerRole,
+          treasurer: authority,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .rpc();
+
+    // A pending bet reserves its maximum payout
+    const session = await placeBet(2 * UNIT);
+    const { pendingLiability } = await program.account.tokenVault.fetch(tokenVault);
+    const free = (await tokenBalance(connection, vaultTokenAccount)) - pendingLiability.toNumber();
+
+    try {
+      await withdraw(free + 1);
+      expect.fail("withdrawal dipped into the liabilities");
+    } catch (err) {
+      expect(`${err}`).to.include("InsufficientVaultLiquidity");
+    }
+
+    const destinationBefore = await tokenBalance(connection, authorityTokenAccount);
+    await withdraw(free);
+    expect(await tokenBalance(connection, vaultTokenAccount)).to.equal(pendingLiability.toNumber());
+    expect(await tokenBalance(connection, authorityTokenAccount)).to.equal(destinationBefore + free);
+
+    const randomValue = new Array(32).fill(0);
+    randomValue[0] = 1;
+    await settle(session, randomValue);
+  });
+});